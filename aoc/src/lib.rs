@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+use util::solution::{erase, DynSolution};
+
+// Returns the solution for the given day, or None if that day hasn't been registered
+pub fn get_solution(day: u8) -> Option<Box<dyn DynSolution>> {
+    Some(match day {
+        1 => erase::<aoc_1::Day1>(),
+        2 => erase::<aoc_2::Day2>(),
+        3 => erase::<aoc_3::Day3>(),
+        4 => erase::<aoc_4::Day4>(),
+        5 => erase::<aoc_5::Day5>(),
+        6 => erase::<aoc_6::Day6>(),
+        7 => erase::<aoc_7::Day7>(),
+        8 => erase::<aoc_8::Day8>(),
+        9 => erase::<aoc_9::Day9>(),
+        10 => erase::<aoc_10::Day10>(),
+        11 => erase::<aoc_11::Day11>(),
+        12 => erase::<aoc_12::Day12>(),
+        13 => erase::<aoc_13::Day13>(),
+        14 => erase::<aoc_14::Day14>(),
+        15 => erase::<aoc_15::Day15>(),
+        16 => erase::<aoc_16::Day16>(),
+        17 => erase::<aoc_17::Day17>(),
+        18 => erase::<aoc_18::Day18>(),
+        19 => erase::<aoc_19::Day19>(),
+        20 => erase::<aoc_20::Day20>(),
+        21 => erase::<aoc_21::Day21>(),
+        22 => erase::<aoc_22::Day22>(),
+        23 => erase::<aoc_23::Day23>(),
+        24 => erase::<aoc_24::Day24>(),
+        25 => erase::<aoc_25::Day25>(),
+        _ => return None,
+    })
+}
+
+// Returns the directory holding the runner and every day's crate
+pub fn get_root_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn get_day_dir(day: u8) -> PathBuf {
+    get_root_dir().join(format!("aoc_{}", day))
+}
+
+// Returns the directory each day keeps its input files (and expected answers) in
+pub fn get_input_dir(day: u8) -> PathBuf {
+    get_day_dir(day).join("input")
+}
+
+pub fn get_default_input_path(day: u8) -> PathBuf {
+    get_input_dir(day).join("input.txt")
+}
@@ -0,0 +1,140 @@
+mod scaffold;
+
+use std::path::PathBuf;
+use structopt::StructOpt;
+use util::file::Cli;
+use util::gen::Rng;
+use util::res::Result;
+use util::solution::DynSolution;
+use util::Error;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "aoc", about = "Runs Advent of Code 2020 solutions")]
+enum Command {
+    /// Runs the solution for a day, on that day's input/input.txt unless another input is given
+    Run {
+        day: u8,
+        #[structopt(flatten)]
+        cli: Cli,
+    },
+    /// Lists the puzzle constants a day's solution lets you override with --param
+    Params {
+        day: u8,
+    },
+    /// Creates the crate for a new day from the templates and registers it with the runner
+    New {
+        day: u8,
+    },
+    /// Generates a random input for a day and prints it, along with the answers it's known to have
+    Gen {
+        day: u8,
+        /// Seed for the generator; the same seed always makes the same input
+        #[structopt(long, default_value = "0")]
+        seed: u64,
+        /// How big an input to make, in a unit that depends on the day (e.g. lines or tiles)
+        #[structopt(long)]
+        size: Option<usize>,
+        /// Writes the input to gen_<seed>.txt in this directory instead, and records its answers in answers.txt there
+        #[structopt(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Runs a day's alternative algorithms against its parts on generated inputs, and reports any disagreement
+    Diff {
+        day: u8,
+        /// How many inputs to generate, with seeds counting up from 0
+        #[structopt(long, default_value = "10")]
+        seeds: u64,
+        /// How big an input to generate, as for gen
+        #[structopt(long)]
+        size: Option<usize>,
+        /// Keeps the inputs that had a disagreement in this directory, instead of a temporary one
+        #[structopt(short, long)]
+        out: Option<PathBuf>,
+    },
+}
+
+fn solution_for(day: u8) -> Result<Box<dyn DynSolution>> {
+    aoc::get_solution(day).ok_or_else(|| Error::usage(format!("No solution for day {}", day)))
+}
+
+fn run(day: u8, cli: Cli) -> Result<()> {
+    let solution = solution_for(day)?;
+    util::solution::run_with_cli(solution.as_ref(), &cli, aoc::get_default_input_path(day))
+}
+
+fn list_params(day: u8) -> Result<()> {
+    let solution = solution_for(day)?;
+    if solution.params().is_empty() {
+        println!("Day {} has no params", day);
+    }
+    solution.params().iter().for_each(|spec| println!("{}", spec));
+
+    Ok(())
+}
+
+// The input goes to stdout (unless it's written to a file) and the answers to stderr, so the
+// input can be piped straight into a file or another command
+fn generate(day: u8, seed: u64, size: Option<usize>, out: Option<PathBuf>) -> Result<()> {
+    let generated = solution_for(day)?.generate(&mut Rng::new(seed), size)?;
+    match out {
+        Some(dir) => {
+            let name = format!("gen_{}.txt", seed);
+            generated.write_to(&dir, &name)?;
+            eprintln!("Wrote {}", dir.join(name).display());
+        },
+        None => print!("{}", generated.input),
+    }
+
+    for (answer, part) in generated.answers.iter().zip(1..) {
+        match answer {
+            Some(answer) => eprintln!("[Part {}] {}", part, answer),
+            None => eprintln!("[Part {}] unknown", part),
+        }
+    }
+
+    Ok(())
+}
+
+// Prints each disagreement along with the input it happened on, and exits with 4 if there were any
+fn diff(day: u8, seeds: u64, size: Option<usize>, out: Option<PathBuf>) -> Result<()> {
+    let solution = solution_for(day)?;
+    let alternatives = solution.alternatives();
+    if alternatives.is_empty() {
+        return Err(Error::usage(format!("Day {} has no alternatives to compare", day)));
+    }
+    for (part, name) in &alternatives {
+        println!("[Part {}] Comparing against {}", part, name);
+    }
+
+    let dir = out.unwrap_or_else(|| std::env::temp_dir().join(format!("aoc_diff_day_{}", day)));
+    let disagreements = util::differential::check_alternatives(solution.as_ref(), 0..seeds, size, &dir)?;
+    disagreements.iter().for_each(|d| println!("{}", d));
+    if !disagreements.is_empty() {
+        std::process::exit(4);
+    }
+
+    println!("All {} inputs agreed", seeds);
+    Ok(())
+}
+
+// Exits with 1 for command line mistakes, 2 if the input was at fault (it couldn't be read or
+// parsed, or isn't a valid puzzle) and 3 if the solution couldn't find an answer. `diff` exits
+// with 4 when alternatives disagree.
+fn main() {
+    let result = match Command::from_args() {
+        Command::Run { day, cli } => run(day, cli),
+        Command::Params { day } => list_params(day),
+        Command::New { day } => scaffold::new_day(day),
+        Command::Gen { day, seed, size, out } => generate(day, seed, size, out),
+        Command::Diff { day, seeds, size, out } => diff(day, seeds, size, out),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(match e.root() {
+            Error::NoSolution(_) => 3,
+            _ if e.is_input_error() => 2,
+            _ => 1,
+        });
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::Path;
+use util::res::{Context, Result};
+use util::Error;
+
+// The files a new day starts with, relative to its crate, and the templates they're made from.
+// `{day}` in a template is replaced with the day number.
+const FILES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../../templates/cargo.txt")),
+    ("src/main.rs", include_str!("../../templates/main.rs")),
+    ("src/lib.rs", include_str!("../../templates/lib.rs")),
+    ("input/answers.txt", include_str!("../../templates/answers.txt")),
+    ("input/example.txt", ""),
+    ("input/input.txt", ""),
+];
+
+// The line each day has in the runner's files, relative to the repository root. Lines for
+// the new day are added among the other days' lines, in order.
+const REGISTRATIONS: &[(&str, &str)] = &[
+    ("aoc/Cargo.toml", "aoc_{day} = { path = \"../aoc_{day}\" }"),
+    ("aoc/src/lib.rs", "        {day} => erase::<aoc_{day}::Day{day}>(),"),
+    ("aoc/tests/answers.rs", "    day_{day}: {day},"),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+// Returns `contents` with the line registering `day` inserted after the lines for earlier days
+fn register(path: &Path, contents: &str, template: &str, day: u8) -> Result<String> {
+    let mut lines = contents.lines().collect::<Vec<&str>>();
+    let registered = lines.iter().enumerate().filter_map(|(idx, line)| {
+        (1..=25).find(|&d| *line == render(template, d)).map(|d| (idx, d))
+    }).collect::<Vec<(usize, u8)>>();
+
+    if registered.iter().any(|&(_, d)| d == day) {
+        return Err(Error::usage(format!("Day {} is already registered in {}", day, path.display())))
+    }
+
+    let insert_at = match (registered.iter().find(|&&(_, d)| d > day), registered.last()) {
+        (Some(&(idx, _)), _) => idx,
+        (None, Some(&(idx, _))) => idx + 1,
+        (None, None) => return Err(Error::usage(format!("Couldn't find where days are registered in {}", path.display()))),
+    };
+
+    let line = render(template, day);
+    lines.insert(insert_at, &line);
+    Ok(lines.join("\n") + if contents.ends_with('\n') { "\n" } else { "" })
+}
+
+// Creates the crate for a new day from the templates and registers it with the runner. Nothing
+// is written unless the day doesn't exist yet and every registration can be made.
+pub fn new_day(day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::usage(format!("Day must be between 1 and 25, got {}", day)))
+    }
+
+    let day_dir = aoc::get_day_dir(day);
+    if day_dir.exists() || aoc::get_solution(day).is_some() {
+        return Err(Error::usage(format!("Day {} already exists", day)))
+    }
+
+    let root = aoc::get_root_dir();
+    let registrations = REGISTRATIONS.iter().map(|(file, template)| {
+        let path = root.join(file);
+        let contents = fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+        Ok((path.clone(), register(&path, &contents, template, day)?))
+    }).collect::<Result<Vec<_>>>()?;
+
+    for (file, template) in FILES {
+        let path = day_dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).with_context(|| format!("Creating {}", path.parent().unwrap().display()))?;
+        fs::write(&path, render(template, day)).with_context(|| format!("Writing {}", path.display()))?;
+        println!("Created {}", path.display());
+    }
+
+    for (path, contents) in registrations {
+        fs::write(&path, contents).with_context(|| format!("Writing {}", path.display()))?;
+        println!("Registered day {} in {}", day, path.display());
+    }
+
+    Ok(())
+}
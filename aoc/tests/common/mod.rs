@@ -0,0 +1,37 @@
+// Helpers shared by the integration tests. Not every test file uses all of them.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// A directory under the system temp dir that's removed when it goes out of scope, including when
+// a test panics partway through
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // `name` has to be different for each test, since the tests in a file run in parallel
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir{ path }
+    }
+
+    pub fn path(&self) -> &Path { &self.path }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// Makes a #[test] named after each case that calls `check` with its argument, e.g.
+//     check_tests!(check_day; day_1: 1, day_2: 2,);
+macro_rules! check_tests {
+    ($check:path; $($name:ident: $arg:expr,)*) => {
+        $(#[test] fn $name() { $check($arg); })*
+    };
+}
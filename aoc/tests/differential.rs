@@ -0,0 +1,24 @@
+// Checks that the days with more than one way of computing an answer always get the same answer
+// each way, on inputs from their generators
+
+#[macro_use]
+mod common;
+
+use common::TempDir;
+
+const SEEDS: u64 = 3;
+
+fn check_day(day: u8) {
+    let solution = aoc::get_solution(day).unwrap();
+    assert!(!solution.alternatives().is_empty(), "Day {} has no alternatives", day);
+
+    let dir = TempDir::new(&format!("differential_day_{}", day));
+    let disagreements = util::differential::check_alternatives(solution.as_ref(), 0..SEEDS, None, dir.path()).unwrap();
+    assert!(disagreements.is_empty(), "Day {} alternatives disagreed:\n    {}", day, disagreements.join("\n    "));
+}
+
+check_tests! { check_day;
+    day_11: 11,
+    day_19: 19,
+    day_25: 25,
+}
@@ -0,0 +1,56 @@
+// Checks every day's input generator: each generated input has to parse, and the solution has
+// to find whichever answers the generator knows it has
+
+#[macro_use]
+mod common;
+
+use common::TempDir;
+use util::gen::Rng;
+
+const SEEDS: u64 = 3;
+
+fn check_day(day: u8) {
+    let solution = aoc::get_solution(day).unwrap();
+    let dir = TempDir::new(&format!("generators_day_{}", day));
+    for seed in 0..SEEDS {
+        let name = format!("gen_{}.txt", seed);
+        let generated = solution.generate(&mut Rng::new(seed), None).unwrap();
+        generated.write_to(dir.path(), &name).unwrap();
+
+        // Inputs with no known answers aren't listed in answers.txt, so they're only parsed here
+        if let Err(e) = solution.parse(dir.path().join(&name)) {
+            panic!("Day {} seed {} didn't parse: {}", day, seed, e);
+        }
+    }
+
+    let mismatches = util::answers::check_answers(solution.as_ref(), dir.path()).unwrap();
+    assert!(mismatches.is_empty(), "Day {} generated answers did not match:\n    {}", day, mismatches.join("\n    "));
+}
+
+check_tests! { check_day;
+    day_1: 1,
+    day_2: 2,
+    day_3: 3,
+    day_4: 4,
+    day_5: 5,
+    day_6: 6,
+    day_7: 7,
+    day_8: 8,
+    day_9: 9,
+    day_10: 10,
+    day_11: 11,
+    day_12: 12,
+    day_13: 13,
+    day_14: 14,
+    day_15: 15,
+    day_16: 16,
+    day_17: 17,
+    day_18: 18,
+    day_19: 19,
+    day_20: 20,
+    day_21: 21,
+    day_22: 22,
+    day_23: 23,
+    day_24: 24,
+    day_25: 25,
+}
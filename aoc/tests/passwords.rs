@@ -0,0 +1,71 @@
+// Checks day 2's password generator: passwords have to come out valid or broken the way they
+// were asked to be, and generated inputs have to have as many valid lines as were planted
+
+#[macro_use]
+mod common;
+
+use std::fs;
+use common::TempDir;
+use aoc_2::gen::{generate_input, password, random_policy};
+use aoc_2::Day2;
+use util::gen::Rng;
+use util::params::Param;
+use util::{Params, Solution};
+
+const SEEDS: u64 = 3;
+const POLICIES: usize = 200;
+
+fn check_passwords(kind: &str) {
+    let mut rng = Rng::new(0);
+    for _ in 0..POLICIES {
+        let policy = random_policy(&mut rng, kind).unwrap();
+        let outcomes = std::iter::once(None).chain(policy.violations().into_iter().map(Some)).collect::<Vec<_>>();
+        for outcome in outcomes {
+            // Not every outcome can be had for every policy (e.g. `1-1 a` can't have both positions)
+            if let Some(password) = password(&mut rng, policy.as_ref(), outcome) {
+                assert_eq!(policy.violations_in(&password), outcome.into_iter().collect::<Vec<_>>(), "{}: {}", policy, password);
+                assert_eq!(policy.is_valid(&password), outcome.is_none(), "{}: {}", policy, password);
+            }
+        }
+    }
+}
+
+fn check_input(kinds: [&str; 2]) {
+    let dir = TempDir::new(&format!("passwords_{}_{}", kinds[0], kinds[1]));
+    let params = Params::new(Day2::PARAMS, &[
+        Param{ key: "part1_policy".to_owned(), value: kinds[0].to_owned() },
+        Param{ key: "part2_policy".to_owned(), value: kinds[1].to_owned() },
+    ]).unwrap();
+
+    for seed in 0..SEEDS {
+        let generated = generate_input(&mut Rng::new(seed), 300, kinds).unwrap();
+        let path = dir.path().join(format!("gen_{}.txt", seed));
+        fs::write(&path, &generated.input).unwrap();
+        let passwords = Day2::parse(path).unwrap();
+        assert_eq!(passwords.lines().len(), 300);
+        assert_eq!(Some(Day2::part1(&passwords, &params, None).unwrap()), generated.answers[0], "{:?} seed {} part 1", kinds, seed);
+        assert_eq!(Some(Day2::part2(&passwords, &params, None).unwrap()), generated.answers[1], "{:?} seed {} part 2", kinds, seed);
+    }
+}
+
+check_tests! { check_passwords;
+    range_passwords: "range",
+    positions_passwords: "positions",
+    classes_passwords: "classes",
+    forbidden_passwords: "forbidden",
+    counts_passwords: "counts",
+}
+
+check_tests! { check_input;
+    range_input: ["range", "range"],
+    positions_input: ["positions", "positions"],
+    classes_input: ["classes", "classes"],
+    forbidden_input: ["forbidden", "forbidden"],
+    counts_input: ["counts", "counts"],
+    range_and_positions_input: ["range", "positions"],
+}
+
+#[test]
+fn mismatched_syntax_is_refused() {
+    assert!(generate_input(&mut Rng::new(0), 10, ["range", "classes"]).is_err());
+}
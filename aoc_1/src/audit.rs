@@ -0,0 +1,80 @@
+use util::res::Result;
+use util::{trace, Answer, Error};
+use crate::ksum::KSum;
+
+// AuditMode is what a part looks for among the expense entries, given how many entries to add
+// up and what they should add up to. The puzzle itself is `product`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditMode {
+    // The product of the first entries found that add up to the target
+    Product,
+    // Every set of entries that adds up to the target, e.g. `1721+299, 979+1041`
+    All,
+    // The entries whose sum is closest to the target, whether or not any add up to it exactly,
+    // e.g. `1721+298=2019`
+    Closest,
+    // The largest or smallest product of entries that add up to the target
+    MaxProduct,
+    MinProduct,
+}
+
+impl std::str::FromStr for AuditMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(AuditMode::Product),
+            "all" => Ok(AuditMode::All),
+            "closest" => Ok(AuditMode::Closest),
+            "max_product" => Ok(AuditMode::MaxProduct),
+            "min_product" => Ok(AuditMode::MinProduct),
+            _ => Err("expected one of product, all, closest, max_product or min_product".to_owned()),
+        }
+    }
+}
+
+fn product(expenses: &[u32], indices: &[usize]) -> Result<i128> {
+    indices.iter().try_fold(1i128, |product, &idx| product.checked_mul(expenses[idx] as i128))
+        .ok_or_else(|| Error::no_solution(format!("The product of {} entries is too large to be an answer", indices.len())))
+}
+
+fn describe(expenses: &[u32], indices: &[usize]) -> String {
+    indices.iter().map(|&idx| expenses[idx].to_string()).collect::<Vec<String>>().join("+")
+}
+
+// Audits the expense report for sets of `k` entries adding up to `target`, the way `mode` asks
+pub fn audit(expenses: &[u32], k: usize, target: u32, mode: AuditMode) -> Result<Answer> {
+    let ksum = KSum::new(expenses);
+    let target = target as i64;
+    let none_found = || Error::no_solution(format!("Did not find {} entries summing to {}", k, target));
+
+    match mode {
+        AuditMode::Product => {
+            let indices = ksum.find(k, target).ok_or_else(none_found)?;
+            trace!(Info, "found", indices = &indices, entries = describe(expenses, &indices));
+            Ok(product(expenses, &indices)?.into())
+        },
+        AuditMode::All => {
+            let all = ksum.find_all(k, target);
+            trace!(Info, "found", count = all.len());
+            match all.is_empty() {
+                true => Err(none_found()),
+                false => Ok(all.iter().map(|indices| describe(expenses, indices)).collect::<Vec<String>>().join(", ").into()),
+            }
+        },
+        AuditMode::Closest => {
+            let indices = ksum.find_closest(k, target)
+                .ok_or_else(|| Error::no_solution(format!("There are fewer than {} entries", k)))?;
+            let sum = indices.iter().map(|&idx| expenses[idx] as i64).sum::<i64>();
+            Ok(format!("{}={}", describe(expenses, &indices), sum).into())
+        },
+        AuditMode::MaxProduct | AuditMode::MinProduct => {
+            let mut products = ksum.find_all(k, target).iter()
+                .map(|indices| product(expenses, indices))
+                .collect::<Result<Vec<i128>>>()?;
+            products.sort_unstable();
+            let chosen = if mode == AuditMode::MaxProduct { products.last() } else { products.first() };
+            chosen.map(|&p| p.into()).ok_or_else(none_found)
+        },
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const TARGET: u32 = 2020;
+
+// Expense reports where exactly one pair and one triple of entries sum to 2020. Every other
+// entry is over half of 2020, so no two of them can be part of a sum on their own, and one
+// that would complete a sum with the planted entries is skipped. `size` is the number of entries.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(200).clamp(5, 900);
+    let (pair, triple) = loop {
+        let a = rng.range(1, TARGET as i64 / 2 - 1) as u32;
+        let x = rng.range(1, TARGET as i64 / 3) as u32;
+        let y = rng.range(1, TARGET as i64 / 3) as u32;
+        let (pair, triple) = ([a, TARGET - a], [x, y, TARGET - x - y]);
+        let planted = pair.iter().chain(triple.iter()).copied().collect::<Vec<u32>>();
+        if planted.iter().collect::<HashSet<&u32>>().len() == planted.len() && count_sums(&planted) == (1, 1) {
+            break (pair, triple)
+        }
+    };
+
+    let mut entries = pair.iter().chain(triple.iter()).copied().collect::<Vec<u32>>();
+    while entries.len() < size {
+        let entry = rng.range(TARGET as i64 / 2 + 1, TARGET as i64 - 1) as u32;
+        if entries.contains(&entry) || completes_sum(&entries, entry) { continue }
+        entries.push(entry);
+    }
+
+    rng.shuffle(&mut entries);
+    let input = entries.iter().map(|e| format!("{}\n", e)).collect();
+    let product = |nums: &[u32]| nums.iter().fold(1u64, |acc, &n| acc * n as u64);
+    Ok(Generated::new(input, Some(product(&pair).into()), Some(product(&triple).into())))
+}
+
+// Whether `entry` would sum to the target with one or two of `entries`
+fn completes_sum(entries: &[u32], entry: u32) -> bool {
+    entries.iter().enumerate().any(|(idx, &a)| {
+        a + entry == TARGET || entries[idx + 1..].iter().any(|&b| a + b + entry == TARGET)
+    })
+}
+
+// How many pairs and triples of `entries` sum to the target
+fn count_sums(entries: &[u32]) -> (usize, usize) {
+    let (mut pairs, mut triples) = (0, 0);
+    for i in 0..entries.len() {
+        for j in i + 1..entries.len() {
+            if entries[i] + entries[j] == TARGET { pairs += 1; }
+            triples += entries[j + 1..].iter().filter(|&&k| entries[i] + entries[j] + k == TARGET).count();
+        }
+    }
+
+    (pairs, triples)
+}
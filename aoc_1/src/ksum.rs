@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+
+// KSum finds sets of k entries that add up to a target. Each entry can only be used once, but
+// different entries with the same value are different entries, so 1010 + 1010 makes 2020 when
+// 1010 is in the list twice. Solutions are given as indices into the original entries.
+//
+// The search meets in the middle: every set of k entries is split into a left half, its k / 2
+// lowest indices, and a right half, the rest. All the halves of each size are listed once, and
+// each left half is matched against the right halves that start after it, looked up by the sum
+// they still need. That takes on the order of n^(k/2) steps rather than trying all n^k sets.
+pub struct KSum {
+    values: Vec<i64>,
+}
+
+// Right halves gathered by their sum, each as ascending indices
+type Halves = BTreeMap<i64, Vec<Vec<usize>>>;
+
+impl KSum {
+    pub fn new<T: Copy + Into<i64>>(entries: &[T]) -> Self {
+        KSum{ values: entries.iter().map(|&e| e.into()).collect() }
+    }
+
+    // The first set of `k` entries found that sum to `target`, as ascending indices
+    pub fn find(&self, k: usize, target: i64) -> Option<Vec<usize>> {
+        let mut found = None;
+        self.sweep(k, &mut |left, sum, right| {
+            if let Some(rest) = right.get(&(target - sum)) {
+                found = Some(joined(left, &rest[0]));
+                return false
+            }
+            true
+        });
+        found
+    }
+
+    // Every set of `k` entries that sum to `target`, each as ascending indices, in ascending order
+    pub fn find_all(&self, k: usize, target: i64) -> Vec<Vec<usize>> {
+        let mut found = vec![];
+        self.sweep(k, &mut |left, sum, right| {
+            for rest in right.get(&(target - sum)).into_iter().flatten() {
+                found.push(joined(left, rest));
+            }
+            true
+        });
+        found.sort_unstable();
+        found
+    }
+
+    // The set of `k` entries whose sum is closest to `target` (the first one found, if several are
+    // as close), as ascending indices. This is only None if there are fewer than `k` entries.
+    pub fn find_closest(&self, k: usize, target: i64) -> Option<Vec<usize>> {
+        let mut best: Option<(i64, Vec<usize>)> = None;
+        self.sweep(k, &mut |left, sum, right| {
+            // The closest right half is the one whose sum is just under or just over what's needed
+            let needed = target - sum;
+            let below = right.range(..=needed).next_back();
+            let above = right.range(needed..).next();
+            for (&rest_sum, rests) in below.into_iter().chain(above) {
+                let distance = (sum + rest_sum - target).abs();
+                if best.as_ref().is_none_or(|(best_distance, _)| distance < *best_distance) {
+                    best = Some((distance, joined(left, &rests[0])));
+                }
+            }
+            best.as_ref().is_none_or(|(distance, _)| *distance > 0)
+        });
+        best.map(|(_, indices)| indices)
+    }
+
+    // Calls `visit` with every left half of a set of `k` entries and its sum, along with all the
+    // right halves that start after it, for as long as `visit` returns true. Left halves are taken
+    // from the one that ends last to the one that ends first, so that right halves only ever need
+    // adding as they come into range.
+    fn sweep(&self, k: usize, visit: &mut dyn FnMut(&[usize], i64, &Halves) -> bool) {
+        let n = self.values.len();
+        if k > n { return }
+
+        // Right halves by the index they start at, and left halves by the index after the one they
+        // end at. An empty half starts at n and ends before 0, so it's always in range.
+        let mut right_by_start = vec![vec![]; n + 1];
+        for (half, sum) in self.halves(k - k / 2) {
+            right_by_start[half.first().copied().unwrap_or(n)].push((half, sum));
+        }
+        let mut left_by_end = vec![vec![]; n + 1];
+        for (half, sum) in self.halves(k / 2) {
+            left_by_end[half.last().map_or(0, |&idx| idx + 1)].push((half, sum));
+        }
+
+        let mut right = Halves::new();
+        for at in (0..=n).rev() {
+            for (half, sum) in right_by_start[at].drain(..) {
+                right.entry(sum).or_default().push(half);
+            }
+            for (half, sum) in &left_by_end[at] {
+                if !visit(half, *sum, &right) { return }
+            }
+        }
+    }
+
+    // Every set of `size` indices, each as ascending indices, with the sum of their values
+    fn halves(&self, size: usize) -> Vec<(Vec<usize>, i64)> {
+        let mut found = vec![];
+        self.extend_half(size, 0, 0, &mut vec![], &mut found);
+        found
+    }
+
+    fn extend_half(&self, size: usize, start: usize, sum: i64, chosen: &mut Vec<usize>, found: &mut Vec<(Vec<usize>, i64)>) {
+        if chosen.len() == size {
+            found.push((chosen.clone(), sum));
+            return
+        }
+
+        for idx in start..self.values.len() {
+            if self.values.len() - idx < size - chosen.len() { break }
+            chosen.push(idx);
+            self.extend_half(size, idx + 1, sum + self.values[idx], chosen, found);
+            chosen.pop();
+        }
+    }
+}
+
+// A left half followed by a right half that starts after it, which keeps the indices ascending
+fn joined(left: &[usize], right: &[usize]) -> Vec<usize> {
+    left.iter().chain(right.iter()).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [u32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    fn sum(entries: &[u32], indices: &[usize]) -> i64 {
+        indices.iter().map(|&idx| entries[idx] as i64).sum()
+    }
+
+    // Every set of `k` indices below `len`, taken from the bits of each mask, in ascending order
+    fn every_set(len: usize, k: usize) -> Vec<Vec<usize>> {
+        (0..1u32 << len)
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| (0..len).filter(|&idx| mask & (1 << idx) != 0).collect())
+            .collect::<std::collections::BTreeSet<Vec<usize>>>()
+            .into_iter().collect()
+    }
+
+    #[test]
+    fn finds_one_to_four_entries() {
+        let ksum = KSum::new(&EXAMPLE);
+        assert_eq!(ksum.find(1, 366), Some(vec![2]));
+        assert_eq!(ksum.find(2, 2020), Some(vec![0, 3]));
+        assert_eq!(ksum.find(3, 2020), Some(vec![1, 2, 4]));
+        assert_eq!(ksum.find(4, 1721 + 979 + 366 + 299), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn finds_all_sets() {
+        let entries = [1, 2, 3, 4, 5, 6u32];
+        let ksum = KSum::new(&entries);
+        for k in 1..=4 {
+            for target in 0..=25 {
+                let expected = every_set(entries.len(), k).into_iter()
+                    .filter(|indices| sum(&entries, indices) == target)
+                    .collect::<Vec<Vec<usize>>>();
+                assert_eq!(ksum.find_all(k, target), expected, "k = {}, target = {}", k, target);
+            }
+        }
+    }
+
+    #[test]
+    fn counts_duplicates_as_different_entries() {
+        let ksum = KSum::new(&[1010, 5, 1010, 1010u32]);
+        assert_eq!(ksum.find(2, 2020).map(|indices| indices.len()), Some(2));
+        assert_eq!(ksum.find_all(2, 2020), vec![vec![0, 2], vec![0, 3], vec![2, 3]]);
+        assert_eq!(ksum.find_all(3, 3030), vec![vec![0, 2, 3]]);
+        assert_eq!(ksum.find_all(1, 1010), vec![vec![0], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn finds_nothing_without_a_solution() {
+        let ksum = KSum::new(&EXAMPLE);
+        assert_eq!(ksum.find(2, 2021), None);
+        assert_eq!(ksum.find_all(3, 1), Vec::<Vec<usize>>::new());
+        assert_eq!(ksum.find_closest(2, 2021).map(|indices| sum(&EXAMPLE, &indices)), Some(2020));
+    }
+
+    #[test]
+    fn finds_nothing_with_too_few_entries() {
+        let ksum = KSum::new(&[1010, 1010u32]);
+        assert_eq!(ksum.find(3, 2020), None);
+        assert_eq!(ksum.find_all(3, 3030), Vec::<Vec<usize>>::new());
+        assert_eq!(ksum.find_closest(3, 2020), None);
+        assert_eq!(KSum::new::<u32>(&[]).find(1, 0), None);
+    }
+
+    #[test]
+    fn finds_the_closest_set() {
+        let ksum = KSum::new(&EXAMPLE);
+        for k in 1..=4 {
+            for target in (0..6000).step_by(37) {
+                let closest = ksum.find_closest(k, target).unwrap();
+                let best = every_set(EXAMPLE.len(), k).iter().map(|indices| (sum(&EXAMPLE, indices) - target).abs()).min().unwrap();
+                assert_eq!((sum(&EXAMPLE, &closest) - target).abs(), best, "k = {}, target = {}", k, target);
+            }
+        }
+    }
+}
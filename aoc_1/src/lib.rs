@@ -0,0 +1,42 @@
+pub mod audit;
+mod gen;
+pub mod ksum;
+
+use std::path::PathBuf;
+use audit::{audit, AuditMode};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "target", default: "2020", description: "Sum the expense entries must add up to" },
+        ParamSpec{ key: "part1_entries", default: "2", description: "How many entries part 1 adds up" },
+        ParamSpec{ key: "part2_entries", default: "3", description: "How many entries part 2 adds up" },
+        ParamSpec{
+            key: "mode",
+            default: "product",
+            description: "What to find among the entries adding up to the target: product (of the first found), all \
+                (of them), closest (to the target, if none add up to it exactly), max_product or min_product",
+        },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Vec<u32>> {
+        util::file::read_lines_to_integers::<u32>(path)
+    }
+
+    fn part1(expenses: &Vec<u32>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        audit(expenses, params.get("part1_entries")?, params.get("target")?, params.get::<AuditMode>("mode")?)
+    }
+
+    fn part2(expenses: &Vec<u32>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        audit(expenses, params.get("part2_entries")?, params.get("target")?, params.get::<AuditMode>("mode")?)
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,33 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// How many ways a run of adapters that each step up by 1 can be chained, by the run's length:
+// a run can skip adapters as long as no step is more than 3 jolts
+const RUN_ARRANGEMENTS: [u64; 5] = [1, 1, 2, 4, 7];
+
+// Adapter bags built from runs of 1-jolt steps (at most 4 long) separated by 3-jolt steps. Runs
+// can be rearranged independently of each other, since a 3-jolt step can't be skipped, so the
+// number of arrangements is the product of each run's. `size` is the number of adapters.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(100).clamp(1, 100);
+    let (mut ones, mut threes, mut arrangements) = (0u64, 0u64, 1u64);
+    let mut adapters: Vec<u64> = vec![];
+    while adapters.len() < size {
+        let run = rng.range(0, 4).min((size - adapters.len()) as i64) as usize;
+        for _ in 0..run {
+            adapters.push(adapters.last().unwrap_or(&0) + 1);
+        }
+
+        ones += run as u64;
+        arrangements *= RUN_ARRANGEMENTS[run];
+        if adapters.len() < size {
+            adapters.push(adapters.last().unwrap_or(&0) + 3);
+            threes += 1;
+        }
+    }
+
+    rng.shuffle(&mut adapters);
+    let input = adapters.iter().map(|a| format!("{}\n", a)).collect();
+    // The device is always 3 jolts above the highest adapter
+    Ok(Generated::new(input, Some((ones * (threes + 1)).into()), Some(arrangements.into())))
+}
@@ -0,0 +1,35 @@
+use util::gen::{Generated, Rng};
+use util::grid::Grid;
+use util::res::Result;
+use util::render::Frames;
+use crate::{
+    adjacent_seats, get_occupied_count_in_stable_arrangement, visible_seats, Tile, ADJACENCY_RULE, LINE_OF_SIGHT_RULE,
+};
+
+// Seat layouts with a quarter of the cells left as floor, which almost always settle under both
+// rules (with less floor, large layouts tend to end up flipping between two arrangements). The
+// odd layout that doesn't settle is drawn again, which takes running it through the solution's
+// automaton (never the direct simulation, which is what the solution gets checked against), but
+// where it settles isn't given as an answer.
+// `size` is the number of rows, and there are a few more columns than that.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let height = size.unwrap_or(90).max(1);
+    let width = height + 8;
+    loop {
+        let seats = Grid::from_fn(height, width, |_, _| if rng.chance(0.25) { Tile::Floor } else { Tile::Empty });
+        if !settles(&seats, ADJACENCY_RULE, adjacent_seats) || !settles(&seats, LINE_OF_SIGHT_RULE, visible_seats) {
+            continue
+        }
+
+        let input = seats.rows().map(|row| row.iter().map(|t| t.to_string()).collect::<String>() + "\n").collect();
+        return Ok(Generated::new(input, None, None))
+    }
+}
+
+fn settles(
+    seats: &Grid<Tile>,
+    rule: &str,
+    neighbours: impl Fn(&Grid<Tile>, (usize, usize)) -> Vec<(usize, usize)>,
+) -> bool {
+    get_occupied_count_in_stable_arrangement(seats, rule, neighbours, &mut Frames::new(None, "")).is_ok()
+}
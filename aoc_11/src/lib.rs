@@ -0,0 +1,169 @@
+mod gen;
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+use util::automaton::{Automaton, BirthSurvival, FiniteTopology};
+use util::render::{Frames, Render, Rgb};
+use util::res::Result;
+use util::grid::{read_grid, Grid};
+use util::gen::{Generated, Rng};
+use util::{Alternative, Answer, Error, Params, Renderer, Solution};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tile {
+    Floor,
+    Empty,
+    Occupied
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Floor),
+            'L' => Some(Tile::Empty),
+            '#' => Some(Tile::Occupied),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+// Floor is drawn light, empty seats green and occupied seats red
+impl Render for Tile {
+    fn glyph(&self) -> char {
+        match self {
+            Tile::Floor => '.',
+            Tile::Empty => 'L',
+            Tile::Occupied => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile::Floor => (224, 224, 224),
+            Tile::Empty => (0, 160, 0),
+            Tile::Occupied => (200, 0, 0),
+        }
+    }
+}
+
+// Seats are the only cells that can change, so floor isn't part of the automaton at all. Empty
+// seats with no occupied neighbours fill up (B0), and occupied seats empty out once too many
+// of their neighbours are occupied.
+const ADJACENCY_RULE: &str = "B0/S0123";
+const LINE_OF_SIGHT_RULE: &str = "B0/S01234";
+
+// Seats and their neighbours affect each other the same way both ways, so (as with any symmetric
+// threshold network) the seating either settles or ends up flipping between two arrangements
+const NEVER_SETTLES: &str = "The seating never settles, it flips between two arrangements forever";
+
+fn is_seat(tile: &Tile) -> bool {
+    tile != &Tile::Floor
+}
+
+fn adjacent_seats(seats: &Grid<Tile>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    seats.neighbours_8(pos).collect()
+}
+
+// The first seat seen in each direction, skipping over floor
+fn visible_seats(seats: &Grid<Tile>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    Grid::<Tile>::directions_8().iter().filter_map(|&direction| {
+        seats.ray(pos, direction).find(|&p| is_seat(&seats[p]))
+    }).collect()
+}
+
+fn get_occupied_count_in_stable_arrangement(
+    seats: &Grid<Tile>,
+    rule: &str,
+    neighbours: impl Fn(&Grid<Tile>, (usize, usize)) -> Vec<(usize, usize)>,
+    frames: &mut Frames,
+) -> Result<usize> {
+    let topology = FiniteTopology::from_grid(seats, is_seat, neighbours);
+    let occupied = seats.iter().filter(|(_, t)| *t == &Tile::Occupied).map(|(pos, _)| pos).collect();
+    let mut automaton = Automaton::new(topology, rule.parse::<BirthSurvival>()?, occupied);
+
+    let draw = |occupied: &HashSet<(usize, usize)>| Grid::from_fn(seats.height(), seats.width(), |row, col| {
+        match &seats[(row, col)] {
+            Tile::Floor => Tile::Floor,
+            _ if occupied.contains(&(row, col)) => Tile::Occupied,
+            _ => Tile::Empty,
+        }
+    });
+
+    frames.write_with(|| draw(automaton.live()))?;
+    let mut before_last = None;
+    loop {
+        let last = automaton.live().clone();
+        if !automaton.step() { break }
+        frames.write_with(|| draw(automaton.live()))?;
+        if before_last.as_ref() == Some(automaton.live()) {
+            return Err(Error::no_solution(NEVER_SETTLES));
+        }
+        before_last = Some(last);
+    }
+
+    Ok(automaton.live().len())
+}
+
+// Runs the seating rules on the whole grid at once, the way the puzzle describes them rather than
+// through the automaton: an empty seat with no occupied neighbours fills up, and an occupied seat
+// with at least `crowded` occupied neighbours empties
+fn simulate_seating(
+    seats: &Grid<Tile>,
+    crowded: usize,
+    neighbours: impl Fn(&Grid<Tile>, (usize, usize)) -> Vec<(usize, usize)>,
+) -> Result<usize> {
+    let to_check = Grid::from_fn(seats.height(), seats.width(), |row, col| neighbours(seats, (row, col)));
+    let mut seats = seats.clone();
+    let mut before_last = None;
+    loop {
+        let next = Grid::from_fn(seats.height(), seats.width(), |row, col| {
+            let occupied = to_check[(row, col)].iter().filter(|&&pos| seats[pos] == Tile::Occupied).count();
+            match &seats[(row, col)] {
+                Tile::Empty if occupied == 0 => Tile::Occupied,
+                Tile::Occupied if occupied >= crowded => Tile::Empty,
+                tile => tile.clone(),
+            }
+        });
+
+        if next == seats { return Ok(seats.count(|tile| tile == &Tile::Occupied)) }
+        if before_last.as_ref() == Some(&next) { return Err(Error::no_solution(NEVER_SETTLES)) }
+        before_last = Some(std::mem::replace(&mut seats, next));
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Tile>;
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[
+        Alternative{ name: "direct simulation", part: 1, run: |seats, _| Ok(simulate_seating(seats, 4, adjacent_seats)?.into()) },
+        Alternative{ name: "direct simulation", part: 2, run: |seats, _| Ok(simulate_seating(seats, 5, visible_seats)?.into()) },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Grid<Tile>> {
+        read_grid(path, Tile::from_char)
+    }
+
+    fn part1(seats: &Grid<Tile>, _params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_occupied_count_in_stable_arrangement(
+            seats, ADJACENCY_RULE, adjacent_seats, &mut Frames::new(renderer, "adjacency"),
+        )?.into())
+    }
+
+    fn part2(seats: &Grid<Tile>, _params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_occupied_count_in_stable_arrangement(
+            seats, LINE_OF_SIGHT_RULE, visible_seats, &mut Frames::new(renderer, "line_of_sight"),
+        )?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,49 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Navigation instructions, with the ship followed both ways as they're written: moved directly
+// by N/S/E/W and turned by L/R (part 1), or steered by a waypoint that N/S/E/W move and L/R
+// rotate about the ship (part 2). `size` is the number of instructions.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(780);
+    let (mut ship, mut heading) = ((0i64, 0i64), (1i64, 0i64));
+    let (mut steered, mut waypoint) = ((0i64, 0i64), (10i64, 1i64));
+    let mut input = String::new();
+    for _ in 0..size {
+        let action = *rng.choose(&['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F']);
+        let value = match action {
+            'L' | 'R' => 90 * rng.range(1, 3),
+            _ => rng.range(1, 100),
+        };
+
+        match action {
+            'L' | 'R' => {
+                // Turning left by 90 degrees takes (x, y) to (-y, x), and a right turn is three of those
+                let turns = if action == 'L' { value / 90 } else { 4 - value / 90 };
+                for _ in 0..turns {
+                    heading = (-heading.1, heading.0);
+                    waypoint = (-waypoint.1, waypoint.0);
+                }
+            },
+            'F' => {
+                ship = (ship.0 + value * heading.0, ship.1 + value * heading.1);
+                steered = (steered.0 + value * waypoint.0, steered.1 + value * waypoint.1);
+            },
+            _ => {
+                let (dx, dy) = match action {
+                    'N' => (0, value),
+                    'S' => (0, -value),
+                    'E' => (value, 0),
+                    _ => (-value, 0),
+                };
+                ship = (ship.0 + dx, ship.1 + dy);
+                waypoint = (waypoint.0 + dx, waypoint.1 + dy);
+            },
+        }
+
+        input.push_str(&format!("{}{}\n", action, value));
+    }
+
+    let distance = |(x, y): (i64, i64)| x.abs() + y.abs();
+    Ok(Generated::new(input, Some(distance(ship).into()), Some(distance(steered).into())))
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Bus notes built around a planted timestamp: each bus goes in the schedule at an offset where
+// it departs that long after the timestamp. Bus IDs are distinct primes, so the timestamp is the
+// earliest that works as long as it's less than their product. Most buses are small primes and
+// two are large, like in real inputs. `size` is the number of buses, up to 9 so that the
+// product fits in a u64.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(9).clamp(2, 9);
+    let primes = (13..1000u64).filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect::<Vec<u64>>();
+    let small = primes.iter().copied().filter(|&p| p < 60).collect::<Vec<u64>>();
+    let large = primes.iter().copied().filter(|&p| p > 300).collect::<Vec<u64>>();
+    let mut ids = rng.sample(&large, 2);
+    ids.extend(rng.sample(&small, size - 2));
+
+    // The large buses are given small offsets (so the schedule isn't mostly x's), by starting
+    // from the first time that fits both of them and adding a random number of their common period
+    let (first, second) = (ids[0], ids[1]);
+    let (first_offset, second_offset) = (rng.below(60), rng.below(60));
+    let fits_both = (0..second).map(|lap| (first - first_offset % first) % first + lap * first)
+        .find(|t| (t + second_offset) % second == 0).unwrap();
+    let product = ids.iter().product::<u64>();
+    let timestamp = fits_both + rng.below(product / (first * second)) * first * second;
+
+    // Offset the bus from the timestamp by how long it takes to depart, plus whole laps of its
+    // route if another bus already has that offset
+    let mut schedule: BTreeMap<u64, u64> = BTreeMap::new();
+    for &id in &ids {
+        let mut offset = (id - timestamp % id) % id;
+        while schedule.contains_key(&offset) { offset += id; }
+        schedule.insert(offset, id);
+    }
+
+    // Your earliest departure is picked so that exactly one bus leaves soonest after it
+    let (earliest, closest) = loop {
+        let earliest = rng.range(100_000, 1_000_000) as u64;
+        let mut waits = ids.iter().map(|&id| ((id - earliest % id) % id, id)).collect::<Vec<(u64, u64)>>();
+        waits.sort_unstable();
+        if waits[0].0 != waits[1].0 { break (earliest, waits[0]) }
+    };
+
+    let last = *schedule.keys().next_back().unwrap();
+    let buses = (0..=last).map(|offset| schedule.get(&offset).map_or("x".to_owned(), |id| id.to_string())).collect::<Vec<String>>();
+    let input = format!("{}\n{}\n", earliest, buses.join(","));
+    Ok(Generated::new(input, Some((closest.0 * closest.1).into()), Some(timestamp.into())))
+}
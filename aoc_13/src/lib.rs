@@ -0,0 +1,68 @@
+mod gen;
+
+use std::path::{Path, PathBuf};
+use util::file::{parse_subslice, GenericParseError};
+use util::math;
+use util::res::Result;
+use util::trace;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+fn parse_input(path: &Path, contents: &str) -> Result<(u64, Vec<(usize, u64)>)> {
+    let lines = contents.lines().collect::<Vec<&str>>();
+    if lines.len() != 2 {
+        return Err(GenericParseError::value(format!("Invalid line count: {}", lines.len())).into())
+    }
+
+    let earliest = parse_subslice::<u64>(lines[0], lines[0].trim()).map_err(|e| e.locate(path, 1, lines[0]))?;
+    let mut bus_ids = vec![];
+    for (idx, relevant_id_str) in lines[1].split(',').enumerate().filter(|(_, id_str)| *id_str != "x") {
+        let id = parse_subslice::<u64>(lines[1], relevant_id_str).map_err(|e| e.locate(path, 2, lines[1]))?;
+        if id == 0 {
+            return Err(GenericParseError::value_in("Bus IDs must be positive", lines[1], relevant_id_str).locate(path, 2, lines[1]).into())
+        }
+        bus_ids.push((idx, id));
+    }
+    if bus_ids.is_empty() {
+        return Err(GenericParseError::value_in("Expected at least one bus ID", lines[1], lines[1]).locate(path, 2, lines[1]).into())
+    }
+
+    Ok((earliest, bus_ids))
+}
+
+fn get_closest_bus_product(earliest: u64, bus_ids: &[(usize, u64)]) -> u64 {
+    let (closest, wait_mins): (u64, u64) = bus_ids.iter().map(|&(_, id)| (id, (id - (earliest % id)) % id))
+        .min_by(|(_, a), (_, b)| a.cmp(b)).unwrap();
+    closest * wait_mins
+}
+
+// Bus `id` leaving `offset` minutes after the timestamp means timestamp ≡ -offset (mod id)
+fn get_earliest_timestamp(bus_ids: &[(usize, u64)]) -> Result<i128> {
+    let congruences = bus_ids.iter().map(|&(offset, id)| (-(offset as i128), id as i128)).collect::<Vec<(i128, i128)>>();
+    trace!(Info, "congruences", count = congruences.len(), first = congruences.first());
+    let (timestamp, period) = math::crt(&congruences)?;
+    trace!(Info, "solved", timestamp = timestamp, repeats_every = period);
+    Ok(timestamp)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (u64, Vec<(usize, u64)>);
+
+    fn parse(path: PathBuf) -> Result<(u64, Vec<(usize, u64)>)> {
+        parse_input(&path, &util::file::read_to_string(path.clone())?)
+    }
+
+    fn part1((earliest, bus_ids): &(u64, Vec<(usize, u64)>), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_closest_bus_product(*earliest, bus_ids).into())
+    }
+
+    fn part2((_, bus_ids): &(u64, Vec<(usize, u64)>), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_earliest_timestamp(bus_ids)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
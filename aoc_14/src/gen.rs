@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const BITS: usize = 36;
+// Each floating bit doubles the addresses a part 2 write touches, so only a few are used
+const MAX_FLOATING: usize = 9;
+
+// Initialization programs, with memory kept for both decoder versions as each write is
+// generated: masking the value (part 1), or writing to every address the mask could make of the
+// address (part 2). `size` is the number of masks.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(100);
+    let (mut memory_v1, mut memory_v2): (HashMap<u64, u64>, HashMap<u64, u64>) = (HashMap::new(), HashMap::new());
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut mask = (0..BITS).map(|_| if rng.chance(0.5) { '1' } else { '0' }).collect::<Vec<char>>();
+        let floating = rng.range(0, MAX_FLOATING as i64) as usize;
+        for bit in rng.sample(&(0..BITS).collect::<Vec<usize>>(), floating) { mask[bit] = 'X'; }
+        input.push_str(&format!("mask = {}\n", mask.iter().collect::<String>()));
+
+        // mask[0] is the most significant bit
+        let bit_is = |c: char| mask.iter().enumerate().filter(|&(_, &m)| m == c).fold(0u64, |acc, (idx, _)| acc | 1 << (BITS - 1 - idx));
+        let (ones, zeros, xs) = (bit_is('1'), bit_is('0'), bit_is('X'));
+
+        for _ in 0..rng.range(1, 6) {
+            let (address, value) = (rng.below(1 << 16), rng.below(1 << 30));
+            input.push_str(&format!("mem[{}] = {}\n", address, value));
+            memory_v1.insert(address, (value & !zeros) | ones);
+
+            let base = (address | ones) & !xs;
+            // Every subset of the floating bits, found by counting down through them
+            let mut subset = xs;
+            loop {
+                memory_v2.insert(base | subset, value);
+                if subset == 0 { break }
+                subset = (subset - 1) & xs;
+            }
+        }
+    }
+
+    let sum = |memory: &HashMap<u64, u64>| memory.values().sum::<u64>();
+    Ok(Generated::new(input, Some(sum(&memory_v1).into()), Some(sum(&memory_v2).into())))
+}
@@ -0,0 +1,148 @@
+mod gen;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use util::res::Result;
+use util::file::{parse_subslice, GenericParseError};
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+pub struct RawMask {
+    zero: Vec<usize>,
+    one: Vec<usize>,
+    x: Vec<usize>,
+}
+
+impl RawMask {
+    fn to_v1(&self) -> MaskV1 {
+        let and = self.zero.iter().fold(u64::MAX, |acc, shift| acc ^ (1 << shift));
+        let or = self.one.iter().fold(0, |acc, shift| acc ^ (1 << shift));
+        MaskV1{and, or}
+    }
+
+    fn to_v2(&self) -> MaskV2 {
+        let or = self.one.iter().fold(0, |acc, shift| acc ^ (1 << shift));
+        let floating_and_mask = self.x.iter().fold(u64::MAX, |acc, shift| acc ^ (1 << shift));
+
+        // Find all possible values for floating bits
+        let mut floating_possibility_or_masks = vec![0];
+        for shift in &self.x {
+            floating_possibility_or_masks = floating_possibility_or_masks.into_iter().flat_map(|incomplete| {
+                vec![incomplete, incomplete | (1 << shift)].into_iter()
+            }).collect();
+        }
+
+        MaskV2{or, floating_and_mask, floating_possibility_or_masks}
+    }
+}
+
+// Version 1 mask, for Part 1
+
+struct MaskV1 {
+    and: u64, // For overwriting with zeroes
+    or: u64, // For overwriting with ones
+}
+
+impl MaskV1 {
+    fn apply(&self, val: u64) -> u64 {
+        (val & self.and) | self.or
+    }
+}
+
+// Version 2 mask, for Part 2
+
+struct MaskV2 {
+    or: u64, // For overwriting with ones
+    floating_and_mask: u64, // For clearing out floating bits before trying another possibility
+    floating_possibility_or_masks: Vec<u64>,
+}
+
+impl MaskV2 {
+    fn apply(&self, val: u64) -> Vec<u64> {
+        self.floating_possibility_or_masks.iter().map(|m| ((val | self.or) & self.floating_and_mask) | m).collect()
+    }
+}
+
+pub enum Instruction {
+    Mem(u64, u64),
+    Mask(RawMask),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match &s.split(" = ").collect::<Vec<&str>>()[..] {
+            ["mask", mask_str] => {
+                let (mut zero, mut one, mut x) = (vec![], vec![], vec![]);
+                mask_str.chars().rev().enumerate().for_each(
+                    |(idx, c)| {
+                        match c {
+                            '1' => one.push(idx),
+                            '0' => zero.push(idx),
+                            _ => x.push(idx),
+                        }
+                    },
+                );
+                Ok(Instruction::Mask(RawMask{zero, one, x}))
+            },
+            [mem_addr_str, mem_val_str] => {
+                let mem_addr = match mem_addr_str.strip_prefix("mem[").and_then(|rem| rem.strip_suffix(']')) {
+                    Some(addr_str) => parse_subslice(s, addr_str)?,
+                    None => return Err(GenericParseError::value_in(
+                        format!("Invalid memory address: {}", mem_addr_str), s, mem_addr_str,
+                    )),
+                };
+                let mem_value = parse_subslice(s, mem_val_str)?;
+                Ok(Instruction::Mem(mem_addr, mem_value))
+            },
+            _ => Err(GenericParseError::value(format!("Invalid instruction: {}", s))),
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+
+    fn parse(path: PathBuf) -> Result<Vec<Instruction>> {
+        util::file::read_lines_to_type::<Instruction>(path)
+    }
+
+    fn part1(instructions: &Vec<Instruction>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let mut curr_mask = MaskV1{and: u64::MAX, or: 0};
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Mask(m) => curr_mask = m.to_v1(),
+                Instruction::Mem(addr, val) => { mem.insert(*addr, curr_mask.apply(*val)); },
+            }
+        }
+
+        let memory_sum: u64 = mem.values().sum();
+        Ok(memory_sum.into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let mut curr_mask = MaskV2{or: 0, floating_and_mask: u64::MAX, floating_possibility_or_masks: vec![]};
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+
+        for instruction in instructions {
+            match instruction {
+                Instruction::Mask(m) => curr_mask = m.to_v2(),
+                Instruction::Mem(addr, val) => {
+                    curr_mask.apply(*addr).into_iter().for_each(|new_addr| { mem.insert(new_addr, *val); });
+                },
+            }
+        }
+
+        let memory_sum: u64 = mem.values().sum();
+        Ok(memory_sum.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,12 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Starting numbers for the memory game, all different and under 20. What gets spoken can only
+// be found by playing the game, so no answers are known. `size` is how many starting numbers
+// there are.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(6).clamp(1, 20);
+    let numbers = rng.sample(&(0..20).collect::<Vec<u64>>(), size);
+    let input = numbers.iter().map(|n| format!("{}\n", n)).collect();
+    Ok(Generated::new(input, None, None))
+}
@@ -0,0 +1,61 @@
+mod gen;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use util::file::GenericParseError;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+fn get_nth_number_spoken(starting_numbers: &[u64], n: usize) -> u64 {
+    let mut prev = 0u64;
+    let mut last_spoken: HashMap<u64, (Option<u64>, u64)> = HashMap::new();
+    for i in 0..n {
+        prev = if i < starting_numbers.len() {
+            starting_numbers[i]
+        } else {
+            match last_spoken.get(&prev).unwrap() {
+                (Some(last_last), last) => last - last_last,
+                (None, _) => 0,
+            }
+        };
+
+        // Update last_spoken now that `prev` has been spoken
+        last_spoken.insert(prev, match last_spoken.get(&prev) {
+            None => (None, i as u64),
+            Some((_, last)) => (Some(*last), i as u64),
+        });
+    }
+
+    prev
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "part1_turns", default: "2020", description: "Turn whose spoken number is part 1's answer" },
+        ParamSpec{ key: "part2_turns", default: "30000000", description: "Turn whose spoken number is part 2's answer" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Vec<u64>> {
+        let starting_numbers = util::file::read_data_lines::<u64>(path)?;
+        if starting_numbers.is_empty() {
+            return Err(GenericParseError::value("Expected at least one starting number").into());
+        }
+        Ok(starting_numbers)
+    }
+
+    fn part1(starting_numbers: &Vec<u64>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_nth_number_spoken(starting_numbers, params.get("part1_turns")?).into())
+    }
+
+    fn part2(starting_numbers: &Vec<u64>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_nth_number_spoken(starting_numbers, params.get("part2_turns")?).into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
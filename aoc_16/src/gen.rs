@@ -0,0 +1,77 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const NAMES: &[&str] = &[
+    "departure location", "departure station", "departure platform", "departure track",
+    "departure date", "departure time", "arrival location", "arrival station", "arrival platform",
+    "arrival track", "class", "duration", "price", "route", "row", "seat", "train", "type", "wagon", "zone",
+];
+const MAX_VALUE: u64 = 999;
+
+// Ticket notes built around a planted mapping of fields to columns. Fields are ranked, and each
+// one's ranges end higher than the last's. Every column has a value on some valid ticket that
+// only its own field and higher-ranked ones allow, so the highest-ranked field has one column it
+// could be, the next one has one left once that's taken, and so on down. Invalid tickets have
+// one value that no field allows. `size` is the number of fields.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(NAMES.len()).clamp(1, NAMES.len());
+    let names = rng.sample(NAMES, size);
+
+    // (lower1, upper1, lower2, upper2) for each field, by rank
+    let mut ranges: Vec<(u64, u64, u64, u64)> = vec![];
+    let mut top = rng.range(100, 200) as u64;
+    for _ in 0..size {
+        let lower = rng.range(25, 50) as u64;
+        let gap_start = rng.range(lower as i64 + 1, top as i64 - 10) as u64;
+        let gap_end = rng.range(gap_start as i64 + 1, gap_start as i64 + 9) as u64;
+        ranges.push((lower, gap_start - 1, gap_end + 1, top));
+        top += rng.range(20, 35) as u64;
+    }
+    let highest = ranges.iter().map(|r| r.3).max().unwrap();
+    let lowest = ranges.iter().map(|r| r.0).min().unwrap();
+
+    // columns[c] is the rank of the field in column c
+    let mut columns = (0..size).collect::<Vec<usize>>();
+    rng.shuffle(&mut columns);
+    let valid_ticket = |rng: &mut Rng| columns.iter().map(|&rank| {
+        let (lower1, upper1, lower2, upper2) = ranges[rank];
+        match rng.chance(0.5) {
+            true => rng.range(lower1 as i64, upper1 as i64) as u64,
+            false => rng.range(lower2 as i64, upper2 as i64) as u64,
+        }
+    }).collect::<Vec<u64>>();
+
+    let yours = valid_ticket(rng);
+    let mut nearby = (0..240).map(|_| valid_ticket(rng)).collect::<Vec<Vec<u64>>>();
+    for (column, &rank) in columns.iter().enumerate() {
+        let ticket = rng.index(nearby.len());
+        nearby[ticket][column] = ranges[rank].3;
+    }
+
+    // Spoil some tickets, other than the ones the planted values ended up on
+    let mut error_rate = 0;
+    for ticket in nearby.iter_mut() {
+        if !rng.chance(0.25) || columns.iter().enumerate().any(|(c, &rank)| ticket[c] == ranges[rank].3) { continue }
+        let invalid = match rng.chance(0.5) {
+            true => rng.range(highest as i64 + 1, MAX_VALUE as i64) as u64,
+            false => rng.range(0, lowest as i64 - 1) as u64,
+        };
+        ticket[rng.index(size)] = invalid;
+        error_rate += invalid;
+    }
+
+    let departure_product = columns.iter().zip(&yours).filter(|(&rank, _)| names[rank].starts_with("departure"))
+        .map(|(_, &value)| value).product::<u64>();
+
+    let mut rules = names.iter().zip(&ranges).map(|(name, (lower1, upper1, lower2, upper2))| {
+        format!("{}: {}-{} or {}-{}", name, lower1, upper1, lower2, upper2)
+    }).collect::<Vec<String>>();
+    rng.shuffle(&mut rules);
+    let ticket_line = |ticket: &[u64]| ticket.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",");
+    let input = format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}\n",
+        rules.join("\n"), ticket_line(&yours), nearby.iter().map(|t| ticket_line(t)).collect::<Vec<String>>().join("\n"),
+    );
+
+    Ok(Generated::new(input, Some(error_rate.into()), Some(departure_product.into())))
+}
@@ -0,0 +1,148 @@
+#[macro_use] extern crate lazy_static;
+
+mod gen;
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use regex::Regex;
+use util::assignment;
+use util::file::{parse_subslice, GenericParseError, Record};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+struct Constraint {
+    name: String,
+    ranges: Vec<std::ops::RangeInclusive<u64>>,
+}
+
+impl std::str::FromStr for Constraint {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        lazy_static! {
+            static ref CONSTRAINT_REGEX: Regex = Regex::new(
+                r"^(?P<name>[^:]+): (?P<lower1>[0-9]+)-(?P<upper1>[0-9]+) or (?P<lower2>[0-9]+)-(?P<upper2>[0-9]+)$"
+            ).unwrap();
+        }
+
+        if let Some(caps) = CONSTRAINT_REGEX.captures(s) {
+            let bound = |name: &str| parse_subslice::<u64>(s, caps.name(name).unwrap().as_str());
+            let (lower1, upper1) = (bound("lower1")?, bound("upper1")?);
+            let (lower2, upper2) = (bound("lower2")?, bound("upper2")?);
+            Ok(Constraint{
+                name: caps.name("name").unwrap().as_str().to_owned(),
+                ranges: vec![(lower1..=upper1), (lower2..=upper2)],
+            })
+        } else {
+            Err(GenericParseError::value(format!("Invalid constraint: {}", s)))
+        }
+    }
+}
+
+impl Constraint {
+    fn check(&self, val: u64) -> bool {
+        self.ranges.iter().any(|r| r.contains(&val))
+    }
+}
+
+struct Ticket {
+    fields: Vec<u64>,
+}
+
+impl std::str::FromStr for Ticket {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Ticket{
+            fields: s.split(',').map(|n| parse_subslice(s, n)).collect::<std::result::Result<Vec<u64>, GenericParseError>>()?,
+        })
+    }
+}
+
+impl Ticket {
+    fn get_out_of_range_count(&self, constraints: &[Constraint]) -> Option<u64> {
+        self.fields.iter().fold(None, |acc, f| {
+            match (acc, constraints.iter().any(|c| c.check(*f))) {
+                (x, true) => x,
+                (None, false) => Some(*f),
+                (Some(s), false) => Some(s + *f),
+            }
+        })
+    }
+}
+
+pub struct Input {
+    constraints: Vec<Constraint>,
+    your_ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+impl Input {
+    // The input has three records: the constraints, "your ticket:" and then "nearby tickets:"
+    fn from_records(records: &[Record]) -> std::result::Result<Input, GenericParseError> {
+        let (constraints, your_ticket, nearby_tickets) = match records {
+            [c, y, n] => (c, y, n),
+            _ => return Err(GenericParseError::value(format!("Expected 3 sections, found {}", records.len()))),
+        };
+
+        for (record, header) in &[(your_ticket, "your ticket:"), (nearby_tickets, "nearby tickets:")] {
+            if record.lines[0] != *header {
+                return Err(record.locate(0, GenericParseError::value(format!("Expected \"{}\"", header))));
+            }
+        }
+
+        Ok(Input{
+            constraints: (0..constraints.lines.len()).map(|idx| constraints.parse_line::<Constraint>(idx))
+                .collect::<std::result::Result<Vec<Constraint>, GenericParseError>>()?,
+            your_ticket: your_ticket.parse_line::<Ticket>(1)?,
+            nearby_tickets: (1..nearby_tickets.lines.len()).map(|idx| nearby_tickets.parse_line::<Ticket>(idx))
+                .collect::<std::result::Result<Vec<Ticket>, GenericParseError>>()?,
+        })
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+
+    fn parse(path: PathBuf) -> Result<Input> {
+        let contents = util::file::read_to_string(path.clone())?;
+        Ok(Input::from_records(&util::file::split_records(&path, &contents))?)
+    }
+
+    fn part1(input: &Input, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let error_rate = input.nearby_tickets.iter().fold(0u64, |acc, t| {
+            acc + t.get_out_of_range_count(&input.constraints).unwrap_or(0)
+        });
+
+        Ok(error_rate.into())
+    }
+
+    fn part2(input: &Input, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let remaining_tickets: Vec<&Ticket> = input.nearby_tickets.iter()
+            .filter(|t| t.get_out_of_range_count(&input.constraints).is_none()).collect();
+
+        // Each field could be at any ticket index whose value is valid for it on every remaining ticket
+        let field_count = input.your_ticket.fields.len();
+        let candidates: HashMap<String, HashSet<usize>> = input.constraints.iter().map(|constraint| {(
+            constraint.name.clone(),
+            (0..field_count).filter(|&idx| {
+                remaining_tickets.iter().all(|t| t.fields.get(idx).is_some_and(|f| constraint.check(*f)))
+            }).collect(),
+        )}).collect();
+
+        let field_name_to_index = assignment::solve(&candidates).into_unique()?;
+
+        // Find the product of the fields starting with "departure" on your ticket
+        let product = field_name_to_index.iter().filter(|(k, _)| k.starts_with("departure"))
+            .fold(1, |acc, (_, idx)| acc * input.your_ticket.fields[*idx]);
+
+        Ok(product.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
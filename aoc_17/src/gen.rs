@@ -0,0 +1,13 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Starting slices of Conway cubes. How many are active after the boot cycles can only be found
+// by running them, so no answers are known. `size` is the width and height of the slice.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(8).max(1);
+    let input = (0..size).map(|_| {
+        (0..size).map(|_| if rng.chance(0.5) { '#' } else { '.' }).chain(std::iter::once('\n')).collect::<String>()
+    }).collect();
+
+    Ok(Generated::new(input, None, None))
+}
@@ -0,0 +1,88 @@
+mod gen;
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
+use util::automaton::{Automaton, BirthSurvival, Lattice};
+use util::grid::{read_grid, Grid};
+use util::render::Frames;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+// Conway cubes: active cubes stay active with 2 or 3 active neighbours, and inactive cubes
+// become active with exactly 3
+const CONWAY_CUBES: &str = "B3/S23";
+
+// Runs the starting slice, embedded in the z = 0 plane (and w = 0 in 4D), for `cycles` cycles
+fn active_after_cycles<const N: usize>(
+    starting_state: &HashSet<(i64, i64, i64)>,
+    cycles: usize,
+    frames: &mut Frames,
+) -> Result<usize> {
+    let active = starting_state.iter().map(|&(x, y, z)| {
+        let mut coords = [0; N];
+        coords[..3].copy_from_slice(&[x, y, z]);
+        coords
+    }).collect();
+
+    let mut automaton = Automaton::new(Lattice::<N>::new(), CONWAY_CUBES.parse::<BirthSurvival>()?, active);
+    frames.write_with(|| draw_slices(automaton.live()))?;
+    for _ in 0..cycles {
+        automaton.step();
+        frames.write_with(|| draw_slices(automaton.live()))?;
+    }
+
+    Ok(automaton.live().len())
+}
+
+// Draws each x/y slice holding active cubes side by side, ordered by z (then w), all over the
+// same x/y range so that they line up
+fn draw_slices<const N: usize>(active: &HashSet<[i64; N]>) -> Grid<Option<bool>> {
+    let slices = active.iter().map(|c| c[2..].to_vec()).collect::<BTreeSet<Vec<i64>>>().into_iter().collect::<Vec<Vec<i64>>>();
+    let (min_x, max_x) = (active.iter().map(|c| c[0]).min().unwrap_or(0), active.iter().map(|c| c[0]).max().unwrap_or(-1));
+    let (min_y, max_y) = (active.iter().map(|c| c[1]).min().unwrap_or(0), active.iter().map(|c| c[1]).max().unwrap_or(-1));
+    let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+
+    // Slices are separated by a column of gaps
+    Grid::from_fn(height, (slices.len() * (width + 1)).saturating_sub(1), |row, col| {
+        let (slice, slice_col) = (&slices[col / (width + 1)], col % (width + 1));
+        if slice_col == width { return None }
+
+        let mut coords = [0; N];
+        coords[0] = min_x + slice_col as i64;
+        coords[1] = min_y + row as i64;
+        coords[2..].copy_from_slice(slice);
+        Some(active.contains(&coords))
+    })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = HashSet<(i64, i64, i64)>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "cycles", default: "6", description: "Number of cycles to run the pocket dimension for" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<HashSet<(i64, i64, i64)>> {
+        let active = read_grid(path, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(active.iter().filter(|(_, &a)| a).map(|((y, x), _)| (x as i64, y as i64, 0i64)).collect())
+    }
+
+    fn part1(starting_state: &HashSet<(i64, i64, i64)>, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(active_after_cycles::<3>(starting_state, params.get("cycles")?, &mut Frames::new(renderer, "3d"))?.into())
+    }
+
+    fn part2(starting_state: &HashSet<(i64, i64, i64)>, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(active_after_cycles::<4>(starting_state, params.get("cycles")?, &mut Frames::new(renderer, "4d"))?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
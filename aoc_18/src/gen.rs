@@ -0,0 +1,62 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Lines are redrawn if either value gets bigger than this, to keep the sums well within an i64
+const MAX_VALUE: i128 = 1_000_000_000_000;
+
+// Homework built one expression at a time, with its value worked out both ways while it's
+// written: evaluating left to right (part 1), and with every + done before any * (part 2).
+// `size` is the number of lines.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(370);
+    let (mut left_to_right, mut addition_first) = (0i128, 0i128);
+    let mut lines = vec![];
+    while lines.len() < size {
+        let (text, value1, value2) = expression(rng, 0);
+        if value1 > MAX_VALUE || value2 > MAX_VALUE { continue }
+        left_to_right += value1;
+        addition_first += value2;
+        lines.push(format!("{}\n", text));
+    }
+
+    Ok(Generated::new(lines.concat(), Some(left_to_right.into()), Some(addition_first.into())))
+}
+
+// An expression of 2-6 terms, with its value under each set of rules. Values saturate rather
+// than overflow, so that oversized ones can be thrown away.
+fn expression(rng: &mut Rng, depth: usize) -> (String, i128, i128) {
+    let (mut text, mut left_to_right, mut sum) = term(rng, depth);
+    // With + first, the value is a product of sums: those finished so far, and the one being added to
+    let mut product = 1i128;
+    for _ in 1..rng.range(2, 6) {
+        let (term_text, term1, term2) = term(rng, depth);
+        match rng.chance(0.5) {
+            true => {
+                text = format!("{} + {}", text, term_text);
+                left_to_right = left_to_right.saturating_add(term1);
+                sum = sum.saturating_add(term2);
+            },
+            false => {
+                text = format!("{} * {}", text, term_text);
+                left_to_right = left_to_right.saturating_mul(term1);
+                product = product.saturating_mul(sum);
+                sum = term2;
+            },
+        }
+    }
+
+    (text, left_to_right, product.saturating_mul(sum))
+}
+
+fn term(rng: &mut Rng, depth: usize) -> (String, i128, i128) {
+    match depth < 3 && rng.chance(0.2) {
+        true => {
+            let (text, value1, value2) = expression(rng, depth + 1);
+            (format!("({})", text), value1, value2)
+        },
+        false => {
+            let n = rng.range(2, 9) as i128;
+            (n.to_string(), n, n)
+        },
+    }
+}
@@ -0,0 +1,230 @@
+#[macro_use] extern crate lazy_static;
+mod gen;
+
+use std::collections::{HashSet, VecDeque};
+use std::ops::Range;
+use std::path::PathBuf;
+use regex::Regex;
+use util::res::Result;
+use util::file::{parse_subslice, GenericParseError};
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Binop {
+    Add,
+    Mul
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Binop(Binop),
+    Num(i64),
+}
+
+// Any other non-space char is picked up as a token of its own, so that it can be pointed at
+fn tokenize(s: &str) -> std::result::Result<Vec<(Token, Range<usize>)>, GenericParseError> {
+    lazy_static! {
+        static ref TOKEN_REGEX: Regex = Regex::new(r"(\(|\)|\+|\*|[0-9]+|\S)").unwrap();
+    }
+
+    TOKEN_REGEX.find_iter(s).map(|m| {
+        Ok((match m.as_str() {
+            "(" => Token::LParen,
+            ")" => Token::RParen,
+            "+" => Token::Binop(Binop::Add),
+            "*" => Token::Binop(Binop::Mul),
+            n if n.starts_with(|c: char| c.is_ascii_digit()) => Token::Num(parse_subslice(s, n)?),
+            c => return Err(GenericParseError::value_at(format!("Unexpected character: {}", c), m.range())),
+        }, m.range()))
+    }).collect()
+}
+
+// Expression is one line of homework, checked to be a well-formed expression: numbers and
+// parenthesised expressions separated by operators, with every paren matched
+pub struct Expression {
+    tokens: Vec<Token>,
+}
+
+impl std::str::FromStr for Expression {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let mut open_parens: Vec<Range<usize>> = vec![];
+        let mut expecting_operand = true;
+        for (token, span) in &tokens {
+            match (token, expecting_operand) {
+                (Token::Num(_), true) => expecting_operand = false,
+                (Token::LParen, true) => open_parens.push(span.clone()),
+                (Token::Binop(_), false) => expecting_operand = true,
+                (Token::RParen, false) => if open_parens.pop().is_none() {
+                    return Err(GenericParseError::value_at("Closing paren without an opening one", span.clone()))
+                },
+                (_, true) => return Err(GenericParseError::value_at("Expected a number or an opening paren", span.clone())),
+                (_, false) => return Err(GenericParseError::value_at("Expected an operator or a closing paren", span.clone())),
+            }
+        }
+
+        if let Some(span) = open_parens.pop() {
+            return Err(GenericParseError::value_at("Opening paren is never closed", span))
+        }
+        if expecting_operand {
+            return Err(GenericParseError::value_at("Expression ends without a number", s.len()..s.len()))
+        }
+
+        Ok(Expression{ tokens: tokens.into_iter().map(|(token, _)| token).collect() })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Val(i64),
+    Binop(Box<Expr>, Binop, Box<Expr>),
+}
+
+#[derive(Clone, Debug)]
+enum Intermediate {
+    Unresolved(Token),
+    Resolved(Expr),
+}
+
+struct ExprParser {
+    parse_stack: VecDeque<Intermediate>,
+    precedence_rules: Vec<HashSet<Binop>>,
+}
+
+impl ExprParser {
+    fn new(precedence_rules: Vec<HashSet<Binop>>) -> ExprParser {
+        ExprParser{ parse_stack: VecDeque::new(), precedence_rules }
+    }
+
+    fn try_resolve_ops(&self, substack: &VecDeque<Intermediate>) -> std::result::Result<Expr, GenericParseError> {
+        let mut processing = substack.clone();
+        let mut processed: VecDeque<Intermediate> = VecDeque::new();
+        for precedence_level in &self.precedence_rules {
+            while !processing.is_empty() {
+                // Try to turn first 3 elements, which should be of the form A <binop> B, into expr C
+                let first = processing.pop_front();
+                let second = processing.pop_front();
+                let third = processing.pop_front();
+                match (first.clone(), second.clone(), third.clone()) {
+                    (Some(Intermediate::Resolved(r)), None, None) => {
+                        processed.push_back(Intermediate::Resolved(r)); // Only one item left
+                    },
+                    (
+                        Some(Intermediate::Resolved(o1)),
+                        Some(Intermediate::Unresolved(Token::Binop(op))),
+                        Some(Intermediate::Resolved(o2)),
+                    ) => {
+                        if precedence_level.contains(&op) {
+                            processing.push_front(Intermediate::Resolved(Expr::Binop(Box::new(o1), op, Box::new(o2))));
+                        } else {
+                            // Wait to apply this op since it's not in the current precedence level
+                            processed.push_back(first.unwrap());
+                            processed.push_back(second.unwrap());
+                            processing.push_front(third.unwrap()); // Keep 2nd operand to consider next
+                        }
+                    },
+                    x => return Err(GenericParseError::value(
+                        format!("Could not resolve subexpression: [{:?}]", x)
+                    )),
+                }
+            }
+
+            processing = std::mem::take(&mut processed);
+        }
+
+        match &processing.iter().collect::<Vec<&Intermediate>>()[..] {
+            [Intermediate::Resolved(e)] => Ok(e.clone()),
+            _ => Err(GenericParseError::value("Could not resolve expression!"))
+        }
+    }
+
+    fn try_resolve_paren(&mut self) -> std::result::Result<(), GenericParseError> {
+        // Search backwards until we find an unresolved left parentheses
+        let mut in_between: VecDeque<Intermediate> = VecDeque::new();
+        while let Some(prev) = self.parse_stack.pop_back() {
+            in_between.push_front(prev.clone());
+            if let Intermediate::Unresolved(Token::LParen) = prev { break }
+        }
+
+        match in_between.pop_front() {
+            Some(Intermediate::Unresolved(Token::LParen)) => {
+                let resolved = self.try_resolve_ops(&in_between)?;
+                self.parse_stack.push_back(Intermediate::Resolved(resolved));
+                Ok(())
+            },
+            f => Err(GenericParseError::value(
+                format!("Unexpected closing paren succeeding [{:?}, {:?}]", f, in_between)
+            )),
+        }
+    }
+
+    fn parse(&mut self, tokens: Vec<Token>) -> std::result::Result<Expr, GenericParseError> {
+        for token in tokens {
+            match token {
+                Token::LParen | Token::Binop(Binop::Add) | Token::Binop(Binop::Mul) => {
+                    self.parse_stack.push_back(Intermediate::Unresolved(token))
+                },
+                Token::RParen => { self.try_resolve_paren()?; },
+                Token::Num(v) => {
+                    self.parse_stack.push_back(Intermediate::Resolved(Expr::Val(v)));
+                },
+            }
+        }
+        self.try_resolve_ops(&self.parse_stack)
+    }
+}
+
+impl Expr {
+    fn eval(&self) -> i64 {
+        match self {
+            Expr::Val(v) => *v,
+            Expr::Binop(e1, op, e2) => match op {
+                Binop::Add => e1.eval() + e2.eval(),
+                Binop::Mul => e1.eval() * e2.eval(),
+            },
+        }
+    }
+}
+
+fn parse_expressions(expressions: &[Expression], precedence_rules: Vec<HashSet<Binop>>) -> std::result::Result<Vec<Expr>, GenericParseError> {
+    expressions.iter().map(|e| {
+        ExprParser::new(precedence_rules.clone()).parse(e.tokens.clone())
+    }).collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    // Expressions are checked when they're read, but each part groups them by its own precedence
+    type Input = Vec<Expression>;
+
+    fn parse(path: PathBuf) -> Result<Vec<Expression>> {
+        util::file::read_lines_to_type::<Expression>(path)
+    }
+
+    fn part1(expressions: &Vec<Expression>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let expressions = parse_expressions(expressions, vec![
+            vec![Binop::Add, Binop::Mul].into_iter().collect::<HashSet<Binop>>() // +/* have equal precedence
+        ])?;
+        let eval_sum = expressions.iter().fold(0, |acc, e| acc + e.eval());
+        Ok(eval_sum.into())
+    }
+
+    fn part2(expressions: &Vec<Expression>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let expressions = parse_expressions(expressions, vec![
+            vec![Binop::Add].into_iter().collect::<HashSet<Binop>>(), // + has highest precedence
+            vec![Binop::Mul].into_iter().collect::<HashSet<Binop>>(), // * has lower precedence
+        ])?;
+        let eval_sum = expressions.iter().fold(0, |acc, e| acc + e.eval());
+        Ok(eval_sum.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
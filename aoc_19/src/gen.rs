@@ -0,0 +1,109 @@
+use std::collections::{BTreeSet, HashMap};
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Rules 42 and 31 match chunks of this many characters, which the solution relies on
+const CHUNK: usize = 8;
+const RESERVED: &[usize] = &[0, 8, 11, 31, 42];
+
+// Satellite messages whose rules look like real inputs': rule 0 is `8 11`, and rules 42 and 31
+// split every string of 8 a's and b's between them, written as trees of rules over the first
+// character and the rest. A message is made of chunks from either rule, and whether it matches
+// follows from the pattern of chunks: exactly 42 42 31 for the original rules (part 1), or some
+// 42s followed by fewer (but at least one) 31s once rules 8 and 11 loop (part 2). `size` is the
+// number of messages.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(400);
+    let chunks = (0..1u32 << CHUNK).map(|bits| {
+        (0..CHUNK).map(|bit| if (bits >> bit) & 1 == 1 { 'a' } else { 'b' }).collect::<String>()
+    }).collect::<Vec<String>>();
+    let (rule42, rule31): (Vec<String>, Vec<String>) = chunks.into_iter().partition(|_| rng.chance(0.5));
+
+    let mut rules = Rules::new();
+    rules.build(rule42.iter().map(|s| s.as_str()).collect(), Some(42));
+    rules.build(rule31.iter().map(|s| s.as_str()).collect(), Some(31));
+    rules.lines.push("0: 8 11".to_owned());
+    rules.lines.push("8: 42".to_owned());
+    rules.lines.push("11: 42 31".to_owned());
+    rng.shuffle(&mut rules.lines);
+
+    let (mut original, mut looping) = (0, 0);
+    let mut messages = vec![];
+    for _ in 0..size {
+        let (count42, count31) = (rng.range(1, 5) as usize, rng.range(0, 3) as usize);
+        let mut pattern = [vec![true; count42], vec![false; count31]].concat();
+        // Sometimes swap two chunks, so that a 31 can come before a 42
+        if rng.chance(0.2) {
+            let (i, j) = (rng.index(pattern.len()), rng.index(pattern.len()));
+            pattern.swap(i, j);
+        }
+
+        let mut message = pattern.iter().map(|&is42| rng.choose(if is42 { &rule42 } else { &rule31 }).as_str()).collect::<String>();
+        // Sometimes add a stray character, which no rule can match
+        let stray = rng.chance(0.1);
+        if stray { message.push(*rng.choose(&['a', 'b'])); }
+
+        let ones = pattern.iter().take_while(|&&is42| is42).count();
+        let split = pattern.len() - ones;
+        let matches_looping = !stray && split >= 1 && ones > split && pattern[ones..].iter().all(|&is42| !is42);
+        if !stray && pattern == [true, true, false] { original += 1; }
+        if matches_looping { looping += 1; }
+        messages.push(message);
+    }
+
+    let input = format!("{}\n\n{}\n", rules.lines.join("\n"), messages.join("\n"));
+    Ok(Generated::new(input, Some(original.into()), Some(looping.into())))
+}
+
+// Rules being built, with the index of the rule for each set of strings (so that equal sets
+// share a rule) and of the rules for single characters
+struct Rules {
+    lines: Vec<String>,
+    by_set: HashMap<BTreeSet<String>, usize>,
+    next: usize,
+}
+
+impl Rules {
+    fn new() -> Self {
+        let mut rules = Rules{ lines: vec![], by_set: HashMap::new(), next: 1 };
+        for c in &["a", "b"] {
+            let idx = rules.allocate();
+            rules.lines.push(format!("{}: \"{}\"", idx, c));
+            rules.by_set.insert(vec![c.to_string()].into_iter().collect(), idx);
+        }
+
+        rules
+    }
+
+    fn allocate(&mut self) -> usize {
+        while RESERVED.contains(&self.next) { self.next += 1; }
+        self.next += 1;
+        self.next - 1
+    }
+
+    // Returns the index of a rule matching exactly `strings`, which all have the same length,
+    // adding it (at `idx` if given) if there isn't one yet
+    fn build(&mut self, strings: BTreeSet<&str>, idx: Option<usize>) -> usize {
+        let key = strings.iter().map(|s| s.to_string()).collect::<BTreeSet<String>>();
+        if let (Some(&existing), None) = (self.by_set.get(&key), idx) {
+            return existing
+        }
+
+        let mut alternatives = vec![];
+        for first in &["a", "b"] {
+            let rests = strings.iter().filter_map(|s| s.strip_prefix(first)).collect::<BTreeSet<&str>>();
+            if rests.is_empty() { continue }
+
+            let first_rule = self.by_set[&vec![first.to_string()].into_iter().collect::<BTreeSet<String>>()];
+            match rests.contains("") {
+                true => alternatives.push(first_rule.to_string()),
+                false => alternatives.push(format!("{} {}", first_rule, self.build(rests, None))),
+            }
+        }
+
+        let idx = idx.unwrap_or_else(|| self.allocate());
+        self.lines.push(format!("{}: {}", idx, alternatives.join(" | ")));
+        self.by_set.insert(key, idx);
+        idx
+    }
+}
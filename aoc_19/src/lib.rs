@@ -0,0 +1,263 @@
+#[macro_use] extern crate lazy_static;
+mod gen;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use regex::Regex;
+use util::gen::{Generated, Rng};
+use util::{file::{parse_subslice, GenericParseError, Record}, res::Result};
+use util::{Alternative, Answer, Params, Renderer, Solution};
+
+#[derive(Clone)]
+pub struct Rule {
+    idx: usize,
+    def: RuleDef,
+}
+
+#[derive(Clone)]
+enum RuleDef {
+    Character(char),
+    OrSequence(Vec<Vec<usize>>),
+}
+
+impl std::str::FromStr for Rule {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RULE_RE: Regex = Regex::new("^([0-9]+): (?:(\"[a-z]\")|([^\"].*))$").unwrap();
+        }
+
+        match RULE_RE.captures(s) {
+            Some(caps) => {
+                let idx = parse_subslice(s, caps.get(1).unwrap().as_str())?;
+                if let Some(ch) = caps.get(2) {
+                    Ok(Rule{idx, def: RuleDef::Character(ch.as_str().chars().nth(1).unwrap())})
+                } else {
+                    let or_sequences = caps.get(3).unwrap().as_str().split('|').map(|seq| {
+                        seq.split_whitespace().map(|n| parse_subslice(s, n))
+                        .collect::<std::result::Result<Vec<usize>, GenericParseError>>()
+                    }).collect::<std::result::Result<Vec<Vec<usize>>, GenericParseError>>()?;
+
+                    Ok(Rule{idx, def: RuleDef::OrSequence(or_sequences)})
+                }
+            },
+            None => Err(GenericParseError::value(format!("Invalid line: {}", s)))
+        }
+    }
+}
+
+// The input has two records: the rules, and then the strings to match against them
+fn parse_input(records: &[Record]) -> std::result::Result<(HashMap<usize, Rule>, Vec<String>), GenericParseError> {
+    let (rules, strings) = match records {
+        [r, s] => (r, s),
+        _ => return Err(GenericParseError::value(format!("Expected 2 sections, found {}", records.len()))),
+    };
+
+    let mut rules_out: HashMap<usize, Rule> = HashMap::new();
+    for idx in 0..rules.lines.len() {
+        let parsed_rule = rules.parse_line::<Rule>(idx)?;
+        rules_out.insert(parsed_rule.idx, parsed_rule);
+    }
+
+    Ok((rules_out, strings.lines.iter().map(|l| l.to_string()).collect()))
+}
+
+struct RuleValueGetter {
+    memoized: HashMap<usize, HashSet<String>>
+}
+
+impl RuleValueGetter {
+    fn new() -> Self { RuleValueGetter{ memoized: HashMap::new() } }
+    fn get_set_of_values_for_rule(&mut self, rules: &HashMap<usize, Rule>, rule_idx: usize) -> HashSet<String> {
+        if let Some(mem) = self.memoized.get(&rule_idx) {
+            return mem.clone()
+        }
+
+        let ret = match &rules.get(&rule_idx).unwrap().def {
+            RuleDef::Character(c) => {
+                vec![c.to_string()].into_iter().collect()
+            },
+            RuleDef::OrSequence(seqs) => {
+                seqs.iter().flat_map(|seq| {
+                    let first_rule = seq[0];
+                    seq.iter().skip(1).fold(
+                        self.get_set_of_values_for_rule(rules, first_rule),
+                        |acc, next_idx| {
+                            acc.iter().flat_map(|prefix| {
+                                self.get_set_of_values_for_rule(rules, *next_idx).into_iter()
+                                    .map(move |suffix| {
+                                        let mut pre_copy = prefix.clone();
+                                        pre_copy.push_str(&suffix);
+                                        pre_copy
+                                    })
+                            }).collect::<HashSet<String>>()
+                        }
+                    ).into_iter()
+                }).collect::<HashSet<String>>()
+            },
+        };
+
+        self.memoized.insert(rule_idx, ret.clone());
+        ret
+    }
+}
+
+// Returns every index that a match of the rule at rule index `to_check`, starting at start_idx,
+// could end at. A rule can match more than one length of the same string (looping rules always
+// can), so every alternative has to be followed, not just the first one that matches.
+fn matches_rule(rules: &HashMap<usize, Rule>, to_check: usize, s: &str, start_idx: usize) -> Vec<usize> {
+    match &rules.get(&to_check).unwrap().def {
+        RuleDef::Character(c) => {
+            if s[start_idx..].starts_with(*c) { vec![start_idx + 1] } else { vec![] }
+        },
+        RuleDef::OrSequence(seqs) => {
+            let mut ends = seqs.iter().flat_map(|seq| {
+                seq.iter().fold(vec![start_idx], |idxs, other_rule_idx| {
+                    idxs.into_iter().filter(|&idx| idx < s.len())
+                        .flat_map(|idx| matches_rule(rules, *other_rule_idx, s, idx))
+                        .collect()
+                })
+            }).collect::<Vec<usize>>();
+            ends.sort_unstable();
+            ends.dedup();
+            ends
+        }
+    }
+}
+
+fn count_matching_rule_zero(rules: &HashMap<usize, Rule>, strings: &[String]) -> usize {
+    strings.iter().filter(|&s| {
+        matches_rule(rules, 0, s, 0).contains(&s.len())
+    }).count()
+}
+
+// Counts the strings that are values of rule 0, by splitting each one between the values of the
+// rules in rule 0's sequences. Listing rule 0's own values would take millions of strings.
+fn count_values_of_rule_zero(rules: &HashMap<usize, Rule>, strings: &[String]) -> usize {
+    let seqs = match &rules.get(&0).unwrap().def {
+        RuleDef::Character(c) => return strings.iter().filter(|s| **s == c.to_string()).count(),
+        RuleDef::OrSequence(seqs) => seqs,
+    };
+
+    let mut getter = RuleValueGetter::new();
+    let values = seqs.iter().flatten().map(|&idx| (idx, getter.get_set_of_values_for_rule(rules, idx)))
+        .collect::<HashMap<usize, HashSet<String>>>();
+
+    fn splits_into(values: &HashMap<usize, HashSet<String>>, seq: &[usize], s: &str) -> bool {
+        match seq {
+            [] => s.is_empty(),
+            [last] => values[last].contains(s),
+            [first, rest @ ..] => values[first].iter().any(|value| {
+                s.starts_with(value.as_str()) && splits_into(values, rest, &s[value.len()..])
+            }),
+        }
+    }
+
+    strings.iter().filter(|s| seqs.iter().any(|seq| splits_into(&values, seq, s))).count()
+}
+
+// Part 2's rules 8 and 11 in place of the original ones
+fn with_looping_rules(rules: &HashMap<usize, Rule>) -> HashMap<usize, Rule> {
+    let mut looping = rules.clone();
+    looping.insert(8, Rule{ idx: 8, def: RuleDef::OrSequence(vec![vec![42], vec![42, 8]]) });
+    looping.insert(11, Rule{ idx: 11, def: RuleDef::OrSequence(vec![vec![42, 31], vec![42, 11, 31]]) });
+    looping
+}
+
+/**
+ * The initial common chain of rules:
+ *     - 0: 8 | 11
+ *     - 8: 42 | 42 8
+ *     - 11: 42 31 | 42 11 31
+ *
+ * ends up condensing into the following pattern:
+ *     [42]+ 42{N} 31{N}
+ * where N >= 1.
+ *
+ * We will find all possible values for rules 42 and 31,
+ * and then try to chunk input strings into segments matching those.
+ *
+ * It just so happens that all values for rules 42 and 31 are
+ * of length 8, which is helpful for more efficient chunking.
+ *
+ * It also just so happens that the set of values that satisfy
+ * rule 42 are disjoint from the set of values that satisfy rule
+ * 31, which makes it even less complex to check if a string matches.
+ */
+fn count_matching_looping_rule_zero(rules: &HashMap<usize, Rule>, strings: &[String]) -> usize {
+    let mut getter = RuleValueGetter::new();
+    let vals42 = getter.get_set_of_values_for_rule(rules, 42);
+    let vals31 = getter.get_set_of_values_for_rule(rules, 31);
+
+    let mut num_matched = 0;
+    for string in strings {
+        let mut chunks: VecDeque<String> = string.chars().collect::<Vec<char>>()
+            .chunks(8).map(|char_chunk| char_chunk.iter().collect::<String>())
+            .collect::<VecDeque<String>>();
+
+        // First, see what N is by repeatedly taking 8-char substrings from
+        // the end of our string until they no longer match rule 31
+        let mut n = 0;
+        while chunks.len() > 2 {
+            let last_eight = chunks.pop_back().unwrap();
+            if vals31.contains(&last_eight) {
+                n += 1;
+            } else {
+                chunks.push_back(last_eight); // Put it back, it doesn't match rule 31
+                break
+            }
+        }
+
+        if n == 0 { continue } // N needs to be >= 1
+
+        // Next, try to match 8-char substrings from the end of our string
+        // with a possible value for rule 42, N times
+        let mut m = 0; // This should end up equal to N after the loop
+        for _ in 0..n {
+            match chunks.pop_back() {
+                Some(last_eight) if vals42.contains(&last_eight) => m += 1,
+                _ => break,
+            }
+        }
+
+        if m != n { continue } // We couldn't satisfy the suffix of 42{N} 31{N}
+
+        // Finally, all the remaining string chunks should match rule 42, and there should be at least 1
+        if chunks.is_empty() { continue }
+        if chunks.iter().all(|chunk| vals42.contains(chunk)) { num_matched += 1; }
+    }
+
+    num_matched
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<usize, Rule>, Vec<String>);
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[
+        Alternative{ name: "rule value enumeration", part: 1, run: |(rules, strings), _| {
+            Ok(count_values_of_rule_zero(rules, strings).into())
+        }},
+        Alternative{ name: "matches_rule with looping rules", part: 2, run: |(rules, strings), _| {
+            Ok(count_matching_rule_zero(&with_looping_rules(rules), strings).into())
+        }},
+    ];
+
+    fn parse(path: PathBuf) -> Result<(HashMap<usize, Rule>, Vec<String>)> {
+        let contents = util::file::read_to_string(path.clone())?;
+        Ok(parse_input(&util::file::split_records(&path, &contents))?)
+    }
+
+    fn part1((rules, strings): &(HashMap<usize, Rule>, Vec<String>), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(count_matching_rule_zero(rules, strings).into())
+    }
+
+    fn part2((rules, strings): &(HashMap<usize, Rule>, Vec<String>), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(count_matching_looping_rule_zero(rules, strings).into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,270 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+use util::Error;
+use crate::policy::*;
+
+// How many candidates are tried before giving up on making a password
+const ATTEMPTS: usize = 200;
+
+// Chars to pad passwords out with, other than whatever a policy is about
+const FILLER: &str = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+// A random password that's valid under `policy` (for None) or breaks it by `violation` and
+// nothing else. None if no such password turned up, e.g. TooFew for a rule that allows 0.
+pub fn password(rng: &mut Rng, policy: &dyn PasswordGenerator, violation: Option<Violation>) -> Option<String> {
+    password_for(rng, &[(policy, violation)])
+}
+
+// Like password, for several policies read from the same line, each wanting its own outcome.
+// Candidates are aimed at the first policy's outcome.
+pub fn password_for(rng: &mut Rng, wanted: &[(&dyn PasswordGenerator, Option<Violation>)]) -> Option<String> {
+    let (first, violation) = wanted.first()?;
+    (0..ATTEMPTS).map(|_| first.candidate(rng, *violation)).find(|password| {
+        !password.is_empty() && wanted.iter().all(|(policy, violation)| {
+            policy.violations_in(password) == violation.iter().copied().collect::<Vec<Violation>>()
+        })
+    })
+}
+
+// Parses `policy` as the kind named `kind`, as something passwords can be made for
+pub fn generator(kind: &str, policy: &str) -> Result<Box<dyn PasswordGenerator>> {
+    Ok((policy_kind(kind)?.parse)(policy, policy)?)
+}
+
+// A random policy of the kind named `kind`
+pub fn random_policy(rng: &mut Rng, kind: &str) -> Result<Box<dyn PasswordGenerator>> {
+    Ok((policy_kind(kind)?.random)(rng))
+}
+
+// Password lists for policies read as `kinds`, one kind per part. Each line's outcome under each
+// kind is picked before its password is made, so the number of valid lines is known for both
+// parts when run with those kinds. The kinds have to read the same syntax, so that one policy
+// can be written for both (they're the same kind, or range and positions). `size` is the number
+// of lines.
+pub fn generate_input(rng: &mut Rng, size: usize, kinds: [&str; 2]) -> Result<Generated> {
+    let (first, second) = (policy_kind(kinds[0])?, policy_kind(kinds[1])?);
+    if first.syntax != second.syntax {
+        return Err(Error::usage(format!("{} and {} policies can't be read from the same line", first.name, second.name)));
+    }
+
+    let mut valid = [0usize; 2];
+    let mut input = String::new();
+    let mut lines = 0;
+    while lines < size {
+        // Positions start from 1, so a range policy allowing 0 can't always be read as positions
+        let text = random_policy(rng, first.name)?.to_string();
+        let policies = match kinds.iter().map(|kind| generator(kind, &text)).collect::<Result<Vec<_>>>() {
+            Ok(policies) => policies,
+            Err(_) => continue,
+        };
+
+        // Both parts reading the policy the same way have to agree on the outcome
+        let mut outcomes = policies.iter().map(|policy| match rng.chance(0.5) {
+            true => None,
+            false => Some(*rng.choose(&policy.violations())),
+        }).collect::<Vec<Option<Violation>>>();
+        if first.name == second.name { outcomes[1] = outcomes[0]; }
+        let wanted = policies.iter().map(|p| p.as_ref()).zip(outcomes.iter().copied()).collect::<Vec<_>>();
+        if let Some(password) = password_for(rng, &wanted) {
+            outcomes.iter().zip(valid.iter_mut()).for_each(|(outcome, count)| if outcome.is_none() { *count += 1 });
+            input.push_str(&format!("{}: {}\n", text, password));
+            lines += 1;
+        }
+    }
+
+    Ok(Generated::new(input, Some(valid[0].into()), Some(valid[1].into())))
+}
+
+// Password lists under the puzzle's own policies. `size` is the number of lines.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    generate_input(rng, size.unwrap_or(1000), ["range", "positions"])
+}
+
+// `len` random filler chars, leaving out any in `avoid`
+fn filler(rng: &mut Rng, avoid: &[char], len: usize) -> Vec<char> {
+    let chars = FILLER.chars().filter(|c| !avoid.contains(c)).collect::<Vec<char>>();
+    (0..len).map(|_| *rng.choose(&chars)).collect()
+}
+
+// How many of something a rule's candidate should have, to keep within lower-upper or to miss it
+fn count_for(rng: &mut Rng, lower: usize, upper: usize, rule: usize, violation: Option<Violation>) -> usize {
+    match violation {
+        Some(Violation::TooFew{ rule: r }) if r == rule && lower > 0 => rng.range(0, lower as i64 - 1) as usize,
+        Some(Violation::TooMany{ rule: r }) if r == rule => rng.range(upper as i64 + 1, upper as i64 + 3) as usize,
+        _ => rng.range(lower as i64, upper as i64) as usize,
+    }
+}
+
+fn bound_violations(rule: usize, lower: usize) -> Vec<Violation> {
+    let too_few = if lower > 0 { vec![Violation::TooFew{ rule }] } else { vec![] };
+    too_few.into_iter().chain(std::iter::once(Violation::TooMany{ rule })).collect()
+}
+
+fn random_letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+fn random_bounds(rng: &mut Rng) -> (usize, usize) {
+    let lower = rng.range(0, 4);
+    (lower as usize, rng.range(lower, lower + 6) as usize)
+}
+
+// How many rules a random policy made of several gets
+fn random_rule_count(rng: &mut Rng) -> usize {
+    rng.range(1, 3) as usize
+}
+
+fn shuffled(rng: &mut Rng, mut chars: Vec<char>) -> String {
+    rng.shuffle(&mut chars);
+    chars.into_iter().collect()
+}
+
+impl LetterRange {
+    pub(crate) fn random(rng: &mut Rng) -> Self {
+        let (lower, upper) = random_bounds(rng);
+        LetterRange{ letter: random_letter(rng), lower, upper }
+    }
+}
+
+impl PasswordGenerator for LetterRange {
+    fn violations(&self) -> Vec<Violation> {
+        bound_violations(0, self.lower)
+    }
+
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String {
+        let count = count_for(rng, self.lower, self.upper, 0, violation);
+        let padding = rng.range(0, 6) as usize;
+        let mut chars = filler(rng, &[self.letter], padding);
+        chars.extend(std::iter::repeat_n(self.letter, count));
+        shuffled(rng, chars)
+    }
+}
+
+impl LetterPositions {
+    pub(crate) fn random(rng: &mut Rng) -> Self {
+        let first = rng.range(1, 8);
+        LetterPositions{ letter: random_letter(rng), first: first as usize, second: rng.range(first + 1, first + 8) as usize }
+    }
+}
+
+impl PasswordGenerator for LetterPositions {
+    fn violations(&self) -> Vec<Violation> {
+        vec![Violation::BothPositions, Violation::NeitherPosition]
+    }
+
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String {
+        let len = self.first.max(self.second) + rng.range(0, 4) as usize;
+        let mut chars = filler(rng, &[self.letter], len);
+        let holding = match violation {
+            Some(Violation::BothPositions) => vec![self.first, self.second],
+            Some(_) => vec![],
+            None => vec![*rng.choose(&[self.first, self.second])],
+        };
+        holding.iter().for_each(|&position| chars[position - 1] = self.letter);
+        chars.into_iter().collect()
+    }
+}
+
+// Chars to make up candidates for each class with, including a few from outside ASCII
+fn class_chars(class: CharClass) -> &'static str {
+    match class {
+        CharClass::Lower => "abcdefghijklmnopqrstuvwxyzéß",
+        CharClass::Upper => "ABCDEFGHIJKLMNOPQRSTUVWXYZÉ",
+        CharClass::Digit => "0123456789٣",
+        CharClass::Letter => "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZé",
+        CharClass::Symbol => "!@#$%&*-_+=?",
+        CharClass::Space => " ",
+    }
+}
+
+impl ClassCounts {
+    pub(crate) fn random(rng: &mut Rng) -> Self {
+        let rule_count = random_rule_count(rng);
+        let classes = rng.sample(&[CharClass::Lower, CharClass::Upper, CharClass::Digit, CharClass::Letter, CharClass::Symbol, CharClass::Space], rule_count);
+        ClassCounts{ rules: classes.into_iter().map(|class| {
+            let (lower, upper) = random_bounds(rng);
+            (class, lower, upper)
+        }).collect() }
+    }
+}
+
+impl PasswordGenerator for ClassCounts {
+    fn violations(&self) -> Vec<Violation> {
+        self.rules.iter().enumerate().flat_map(|(rule, &(_, lower, _))| bound_violations(rule, lower)).collect()
+    }
+
+    // Every char belongs to some class, so there's no neutral filler. A few chars from a class
+    // none of the rules name pad it out instead, which can still miss where classes overlap.
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String {
+        let mut chars = vec![];
+        for (rule, &(class, lower, upper)) in self.rules.iter().enumerate() {
+            let sample = class_chars(class).chars().collect::<Vec<char>>();
+            let count = count_for(rng, lower, upper, rule, violation);
+            chars.extend((0..count).map(|_| *rng.choose(&sample)));
+        }
+
+        let unnamed = [CharClass::Lower, CharClass::Upper, CharClass::Digit, CharClass::Symbol].iter()
+            .filter(|class| !self.rules.iter().any(|(named, _, _)| named == *class))
+            .map(|&class| class_chars(class)).collect::<Vec<&str>>();
+        if !unnamed.is_empty() {
+            let sample = rng.choose(&unnamed).chars().collect::<Vec<char>>();
+            let padding = rng.range(0, 4) as usize;
+            chars.extend((0..padding).map(|_| *rng.choose(&sample)));
+        }
+        shuffled(rng, chars)
+    }
+}
+
+impl Forbidden {
+    pub(crate) fn random(rng: &mut Rng) -> Self {
+        let rule_count = random_rule_count(rng);
+        Forbidden{ substrings: (0..rule_count).map(|_| {
+            let len = rng.range(2, 3) as usize;
+            filler(rng, &[], len).into_iter().collect()
+        }).collect() }
+    }
+}
+
+impl PasswordGenerator for Forbidden {
+    fn violations(&self) -> Vec<Violation> {
+        (0..self.substrings.len()).map(|rule| Violation::Forbidden{ rule }).collect()
+    }
+
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String {
+        let len = rng.range(3, 10) as usize;
+        let mut chars = filler(rng, &[], len);
+        if let Some(Violation::Forbidden{ rule }) = violation {
+            let at = rng.index(chars.len() + 1);
+            chars.splice(at..at, self.substrings[rule].chars());
+        }
+        chars.into_iter().collect()
+    }
+}
+
+impl LetterCounts {
+    pub(crate) fn random(rng: &mut Rng) -> Self {
+        let rule_count = random_rule_count(rng);
+        let letters = rng.sample(&(b'a'..=b'z').map(|b| b as char).collect::<Vec<char>>(), rule_count);
+        LetterCounts{ counts: letters.into_iter().map(|letter| {
+            let (lower, upper) = random_bounds(rng);
+            LetterRange{ letter, lower, upper }
+        }).collect() }
+    }
+}
+
+impl PasswordGenerator for LetterCounts {
+    fn violations(&self) -> Vec<Violation> {
+        self.counts.iter().enumerate().flat_map(|(rule, count)| bound_violations(rule, count.lower)).collect()
+    }
+
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String {
+        let letters = self.counts.iter().map(|range| range.letter).collect::<Vec<char>>();
+        let padding = rng.range(0, 6) as usize;
+        let mut chars = filler(rng, &letters, padding);
+        for (rule, range) in self.counts.iter().enumerate() {
+            let count = count_for(rng, range.lower, range.upper, rule, violation);
+            chars.extend(std::iter::repeat_n(range.letter, count));
+        }
+        shuffled(rng, chars)
+    }
+}
@@ -0,0 +1,102 @@
+pub mod gen;
+pub mod policy;
+
+use std::path::PathBuf;
+use policy::{policy_kind, PasswordGenerator};
+use util::file::GenericParseError;
+use util::report::{Report, ReportLine, Verdict};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+// PasswordLine is one line of the input: a policy, then `: `, then the password it applies to.
+// The password is everything after the first `: `, so it can hold any text. How the policy is
+// read depends on the kind of policy a part is run with.
+pub struct PasswordLine {
+    text: String,
+    split: usize,
+}
+
+impl PasswordLine {
+    pub fn policy(&self) -> &str { &self.text[..self.split] }
+    pub fn password(&self) -> &str { &self.text[(self.split + 2)..] }
+}
+
+impl std::str::FromStr for PasswordLine {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.find(": ") {
+            Some(split) if split + 2 < s.len() => Ok(PasswordLine{ text: s.to_owned(), split }),
+            Some(split) => Err(GenericParseError::value_at("Missing password", split..s.len())),
+            None => Err(GenericParseError::value("Expected <policy>: <password>")),
+        }
+    }
+}
+
+// The lines of an input, along with the file they came from, since their policies are only
+// parsed once a part knows which kind of policy to read them as
+pub struct PasswordList {
+    path: PathBuf,
+    lines: Vec<PasswordLine>,
+}
+
+impl PasswordList {
+    // Parses every line's policy as the kind of policy named `kind`
+    pub fn policies(&self, kind: &str) -> Result<Vec<Box<dyn PasswordGenerator>>> {
+        let kind = policy_kind(kind)?;
+        self.lines.iter().enumerate().map(|(idx, line)| {
+            (kind.parse)(&line.text, line.policy()).map_err(|e| e.locate(&self.path, idx + 1, &line.text).into())
+        }).collect()
+    }
+
+    pub fn lines(&self) -> &[PasswordLine] { &self.lines }
+}
+
+fn count_valid(passwords: &PasswordList, kind: &str) -> Result<usize> {
+    let policies = passwords.policies(kind)?;
+    Ok(passwords.lines.iter().zip(policies.iter()).filter(|(line, policy)| policy.is_valid(line.password())).count())
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = PasswordList;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "part1_policy", default: "range", description: "Kind of policy part 1 reads each line's policy as" },
+        ParamSpec{ key: "part2_policy", default: "positions", description: "Kind of policy part 2 reads each line's policy as" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<PasswordList> {
+        let lines = util::file::read_lines_to_type::<PasswordLine>(path.clone())?;
+        Ok(PasswordList{ path, lines })
+    }
+
+    fn part1(passwords: &PasswordList, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(count_valid(passwords, &params.get::<String>("part1_policy")?)?.into())
+    }
+
+    fn part2(passwords: &PasswordList, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(count_valid(passwords, &params.get::<String>("part2_policy")?)?.into())
+    }
+
+    // Every line's verdict under each part's policy. Only the first rule a password breaks is
+    // given as the reason.
+    fn report(passwords: &PasswordList, params: &Params) -> Result<Report> {
+        let mut kinds = vec![params.get::<String>("part1_policy")?, params.get::<String>("part2_policy")?];
+        kinds.dedup();
+        let policies = kinds.iter().map(|kind| passwords.policies(kind)).collect::<Result<Vec<_>>>()?;
+
+        Ok(Report{ lines: passwords.lines.iter().enumerate().map(|(idx, line)| ReportLine{
+            line: idx + 1,
+            text: line.text.clone(),
+            verdicts: kinds.iter().zip(policies.iter()).map(|(kind, policies)| {
+                Verdict::new(kind, policies[idx].check(line.password()).map_err(|failures| failures[0].reason.clone()))
+            }).collect(),
+        }).collect() })
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
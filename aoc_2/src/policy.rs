@@ -0,0 +1,365 @@
+use std::fmt;
+use util::file::{parse_subslice, GenericParseError};
+use util::gen::Rng;
+use util::res::Result;
+use util::Error;
+
+type ParseResult<T> = std::result::Result<T, GenericParseError>;
+type CheckResult = std::result::Result<(), Vec<Failure>>;
+
+// Violation is one way a password can break its policy. Rules are numbered from 0 in the order
+// the policy lists them, so a policy with a single rule only has rule 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    // Fewer or more of a letter (or class of chars) than the rule allows
+    TooFew { rule: usize },
+    TooMany { rule: usize },
+    BothPositions,
+    NeitherPosition,
+    // The rule'th forbidden substring appears in the password
+    Forbidden { rule: usize },
+}
+
+// Failure is a rule a password breaks, with the reason, e.g. "letter 'a' appears 5 times, allowed 1-3"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub violation: Violation,
+    pub reason: String,
+}
+
+// PasswordPolicy is a rule a password has to follow, parsed from the part of an input line
+// before the `: `. Passwords are compared char by char, so any Unicode text works, and
+// positions count chars rather than bytes.
+pub trait PasswordPolicy {
+    // Fails with every rule the password breaks, in the order the policy lists them
+    fn check(&self, password: &str) -> CheckResult;
+
+    fn is_valid(&self, password: &str) -> bool {
+        self.check(password).is_ok()
+    }
+
+    // The ways `password` breaks the policy, which is empty when it's valid
+    fn violations_in(&self, password: &str) -> Vec<Violation> {
+        self.check(password).err().unwrap_or_default().into_iter().map(|failure| failure.violation).collect()
+    }
+}
+
+// PasswordGenerator is a policy that passwords can also be made for (see gen). Every kind of
+// policy is one, so the same parsed policy serves for checking and for generating.
+pub trait PasswordGenerator: PasswordPolicy + fmt::Display {
+    // The ways a password could break this policy, e.g. there's no TooFew for `0-2 a`
+    fn violations(&self) -> Vec<Violation>;
+
+    // A random password aimed at breaking the policy by `violation` alone, or at being valid.
+    // It can miss, since rules can get in each other's way (e.g. `1-2 lower, 5-9 letter`, where
+    // lowercase chars are letters too), so it has to be checked against the policy.
+    fn candidate(&self, rng: &mut Rng, violation: Option<Violation>) -> String;
+}
+
+// PolicyKind is one way of reading the policies in an input. Every line of an input is read
+// the same way, and which kind that is gets picked per run (see POLICY_KINDS).
+pub struct PolicyKind {
+    pub name: &'static str,
+    // What the policy part of a line looks like, e.g. `1-3 a`
+    pub syntax: &'static str,
+    pub description: &'static str,
+    // Parses `policy`, which is a slice of `line`, so errors can point at the right text
+    pub parse: fn(line: &str, policy: &str) -> ParseResult<Box<dyn PasswordGenerator>>,
+    // A random policy of this kind
+    pub random: fn(rng: &mut Rng) -> Box<dyn PasswordGenerator>,
+}
+
+pub const POLICY_KINDS: &[PolicyKind] = &[
+    PolicyKind{
+        name: "range",
+        syntax: "1-3 a",
+        description: "The letter appears between 1 and 3 times (the sled rental policy)",
+        parse: |line, policy| Ok(Box::new(LetterRange::parse(line, policy)?)),
+        random: |rng| Box::new(LetterRange::random(rng)),
+    },
+    PolicyKind{
+        name: "positions",
+        syntax: "1-3 a",
+        description: "Exactly one of positions 1 and 3 holds the letter (the toboggan policy)",
+        parse: |line, policy| Ok(Box::new(LetterPositions::parse(line, policy)?)),
+        random: |rng| Box::new(LetterPositions::random(rng)),
+    },
+    PolicyKind{
+        name: "classes",
+        syntax: "1-3 digit, 2-8 upper",
+        description: "Between 1 and 3 chars are digits and between 2 and 8 are uppercase. \
+            Classes are lower, upper, digit, letter, symbol and space",
+        parse: |line, policy| Ok(Box::new(ClassCounts::parse(line, policy)?)),
+        random: |rng| Box::new(ClassCounts::random(rng)),
+    },
+    PolicyKind{
+        name: "forbidden",
+        syntax: "abc,123",
+        description: "Neither abc nor 123 appears anywhere in the password",
+        parse: |line, policy| Ok(Box::new(Forbidden::parse(line, policy)?)),
+        random: |rng| Box::new(Forbidden::random(rng)),
+    },
+    PolicyKind{
+        name: "counts",
+        syntax: "1-3 a, 0-2 b",
+        description: "a appears between 1 and 3 times and b between 0 and 2 times",
+        parse: |line, policy| Ok(Box::new(LetterCounts::parse(line, policy)?)),
+        random: |rng| Box::new(LetterCounts::random(rng)),
+    },
+];
+
+pub fn policy_kind(name: &str) -> Result<&'static PolicyKind> {
+    POLICY_KINDS.iter().find(|kind| kind.name == name).ok_or_else(|| Error::usage(format!(
+        "Unknown policy: {} (expected one of: {})",
+        name, POLICY_KINDS.iter().map(|kind| kind.name).collect::<Vec<&str>>().join(", "),
+    )))
+}
+
+// Parses `N-M`, where `s` is a slice of `line`
+fn parse_bounds(line: &str, s: &str) -> ParseResult<(usize, usize)> {
+    match s.split_once('-') {
+        Some((lower, upper)) => Ok((parse_subslice(line, lower)?, parse_subslice(line, upper)?)),
+        None => Err(GenericParseError::value_in(format!("Expected N-M, found {}", s), line, s)),
+    }
+}
+
+// Parses `N-M x`, where x is whatever follows the space, with N no greater than M
+fn parse_bounded<'a>(line: &str, s: &'a str) -> ParseResult<(usize, usize, &'a str)> {
+    let (bounds, rest) = s.split_once(' ')
+        .ok_or_else(|| GenericParseError::value_in(format!("Expected N-M followed by a space, found {}", s), line, s))?;
+    let (lower, upper) = parse_bounds(line, bounds)?;
+    if lower > upper {
+        return Err(GenericParseError::value_in(format!("{} is more than {}", lower, upper), line, bounds));
+    }
+
+    Ok((lower, upper, rest))
+}
+
+fn parse_letter(line: &str, s: &str) -> ParseResult<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(GenericParseError::value_in(format!("Expected a single letter, found {}", s), line, s)),
+    }
+}
+
+// Policies made of several rules are separated by commas, with optional whitespace around each
+fn split_rules(policy: &str) -> impl Iterator<Item = &str> {
+    policy.split(',').map(|rule| rule.trim())
+}
+
+// How `count` of something misses lower-upper, as rule number `rule`, if it does
+fn count_failure(rule: usize, count: usize, lower: usize, upper: usize, reason: impl FnOnce() -> String) -> Option<Failure> {
+    let violation = match count {
+        count if count < lower => Violation::TooFew{ rule },
+        count if count > upper => Violation::TooMany{ rule },
+        _ => return None,
+    };
+    Some(Failure{ violation, reason: reason() })
+}
+
+fn failed(failures: Vec<Failure>) -> CheckResult {
+    if failures.is_empty() { Ok(()) } else { Err(failures) }
+}
+
+pub struct LetterRange {
+    pub letter: char,
+    pub lower: usize,
+    pub upper: usize,
+}
+
+impl LetterRange {
+    pub(crate) fn parse(line: &str, policy: &str) -> ParseResult<Self> {
+        let (lower, upper, letter) = parse_bounded(line, policy)?;
+        Ok(LetterRange{ letter: parse_letter(line, letter)?, lower, upper })
+    }
+
+    // How the password breaks this range, taken as rule number `rule` of its policy
+    fn failure(&self, rule: usize, password: &str) -> Option<Failure> {
+        let count = password.chars().filter(|&c| c == self.letter).count();
+        count_failure(rule, count, self.lower, self.upper, || {
+            format!("letter {:?} appears {} times, allowed {}-{}", self.letter, count, self.lower, self.upper)
+        })
+    }
+}
+
+impl fmt::Display for LetterRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.lower, self.upper, self.letter)
+    }
+}
+
+impl PasswordPolicy for LetterRange {
+    fn check(&self, password: &str) -> CheckResult {
+        failed(self.failure(0, password).into_iter().collect())
+    }
+}
+
+// Positions are 1-based, and a position past the end of the password doesn't hold the letter
+pub struct LetterPositions {
+    pub letter: char,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl LetterPositions {
+    pub(crate) fn parse(line: &str, policy: &str) -> ParseResult<Self> {
+        let (bounds, letter) = policy.split_once(' ')
+            .ok_or_else(|| GenericParseError::value_in(format!("Expected N-M followed by a space, found {}", policy), line, policy))?;
+        let (first, second) = parse_bounds(line, bounds)?;
+        if first == 0 || second == 0 {
+            return Err(GenericParseError::value_in("Positions start from 1", line, bounds));
+        }
+
+        Ok(LetterPositions{ letter: parse_letter(line, letter)?, first, second })
+    }
+}
+
+impl fmt::Display for LetterPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}", self.first, self.second, self.letter)
+    }
+}
+
+impl PasswordPolicy for LetterPositions {
+    fn check(&self, password: &str) -> CheckResult {
+        let holds_letter = |position: usize| password.chars().nth(position - 1) == Some(self.letter);
+        let (violation, reason) = match (holds_letter(self.first), holds_letter(self.second)) {
+            (true, true) => (Violation::BothPositions, format!("positions {} and {} both contain {:?}", self.first, self.second, self.letter)),
+            (false, false) => (Violation::NeitherPosition, format!("neither position {} nor {} contains {:?}", self.first, self.second, self.letter)),
+            _ => return Ok(()),
+        };
+        Err(vec![Failure{ violation, reason }])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Letter,
+    Symbol,
+    Space,
+}
+
+impl CharClass {
+    const NAMES: &'static [(&'static str, CharClass)] = &[
+        ("lower", CharClass::Lower),
+        ("upper", CharClass::Upper),
+        ("digit", CharClass::Digit),
+        ("letter", CharClass::Letter),
+        ("symbol", CharClass::Symbol),
+        ("space", CharClass::Space),
+    ];
+
+    // Letters and digits from any script count, e.g. `é` is lowercase and `٣` is a digit
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Letter => c.is_alphabetic(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+            CharClass::Space => c.is_whitespace(),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES.iter().find(|(_, class)| *class == self).unwrap().0
+    }
+}
+
+impl std::str::FromStr for CharClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        CharClass::NAMES.iter().find(|(name, _)| *name == s).map(|(_, class)| *class).ok_or_else(|| {
+            let names = CharClass::NAMES.iter().map(|(name, _)| *name).collect::<Vec<&str>>();
+            format!("Unknown character class {} (expected one of: {})", s, names.join(", "))
+        })
+    }
+}
+
+pub struct ClassCounts {
+    pub rules: Vec<(CharClass, usize, usize)>,
+}
+
+impl ClassCounts {
+    pub(crate) fn parse(line: &str, policy: &str) -> ParseResult<Self> {
+        let rules = split_rules(policy).map(|rule| {
+            let (lower, upper, class) = parse_bounded(line, rule)?;
+            Ok((parse_subslice(line, class)?, lower, upper))
+        }).collect::<ParseResult<Vec<(CharClass, usize, usize)>>>()?;
+
+        Ok(ClassCounts{ rules })
+    }
+}
+
+impl fmt::Display for ClassCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules = self.rules.iter().map(|(class, lower, upper)| format!("{}-{} {}", lower, upper, class.name())).collect::<Vec<String>>();
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+impl PasswordPolicy for ClassCounts {
+    fn check(&self, password: &str) -> CheckResult {
+        failed(self.rules.iter().enumerate().filter_map(|(rule, &(class, lower, upper))| {
+            let count = password.chars().filter(|&c| class.contains(c)).count();
+            count_failure(rule, count, lower, upper, || format!("{} {} chars, allowed {}-{}", count, class.name(), lower, upper))
+        }).collect())
+    }
+}
+
+pub struct Forbidden {
+    pub substrings: Vec<String>,
+}
+
+impl Forbidden {
+    pub(crate) fn parse(line: &str, policy: &str) -> ParseResult<Self> {
+        let substrings = split_rules(policy).map(|substring| match substring.is_empty() {
+            true => Err(GenericParseError::value_in("Forbidden substrings can't be empty", line, policy)),
+            false => Ok(substring.to_owned()),
+        }).collect::<ParseResult<Vec<String>>>()?;
+
+        Ok(Forbidden{ substrings })
+    }
+}
+
+impl fmt::Display for Forbidden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.substrings.join(","))
+    }
+}
+
+impl PasswordPolicy for Forbidden {
+    fn check(&self, password: &str) -> CheckResult {
+        failed(self.substrings.iter().enumerate()
+            .filter(|(_, substring)| password.contains(substring.as_str()))
+            .map(|(rule, substring)| Failure{ violation: Violation::Forbidden{ rule }, reason: format!("contains forbidden {:?}", substring) })
+            .collect())
+    }
+}
+
+pub struct LetterCounts {
+    pub counts: Vec<LetterRange>,
+}
+
+impl LetterCounts {
+    pub(crate) fn parse(line: &str, policy: &str) -> ParseResult<Self> {
+        Ok(LetterCounts{ counts: split_rules(policy).map(|rule| LetterRange::parse(line, rule)).collect::<ParseResult<Vec<LetterRange>>>()? })
+    }
+}
+
+impl fmt::Display for LetterCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.counts.iter().map(|count| count.to_string()).collect::<Vec<String>>().join(", "))
+    }
+}
+
+impl PasswordPolicy for LetterCounts {
+    fn check(&self, password: &str) -> CheckResult {
+        failed(self.counts.iter().enumerate().filter_map(|(rule, count)| count.failure(rule, password)).collect())
+    }
+}
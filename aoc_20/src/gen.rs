@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+use util::gen::{Generated, Rng};
+use util::grid::Grid;
+use util::render;
+use util::res::Result;
+
+const MONSTER: &[&str] = &[
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+const TILE: usize = 10;
+const INNER: usize = TILE - 2;
+
+// Camera tiles cut from a known image. The image is random water with sea monsters planted in
+// it (in its original orientation only, with none appearing by chance in any other), so the
+// roughness is every # minus the monsters'. It's cut into tiles whose shared borders are all
+// different, so tiles only fit together one way, and then each tile is flipped and rotated at
+// random. `size` is the number of tiles along each side of the image, up to 15: there are only
+// 496 borders that differ from each other read either way, and a 16x16 image needs 544.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let side = size.unwrap_or(12).clamp(3, 15);
+    let (image, monsters) = loop {
+        if let Some(drawn) = draw_image(rng, side) { break drawn }
+    };
+    let roughness = image.count(|&p| p) - monsters * monster_pixels().len();
+    let tiles = loop {
+        if let Some(tiles) = cut_tiles(rng, &image, side) { break tiles }
+    };
+
+    let ids = rng.sample(&(1000..10000u64).collect::<Vec<u64>>(), side * side);
+    let corners = [(0, 0), (0, side - 1), (side - 1, 0), (side - 1, side - 1)];
+    let corner_product = corners.iter().map(|&(row, col)| ids[row * side + col]).product::<u64>();
+
+    let mut records = tiles.iter().zip(&ids).map(|(tile, id)| {
+        let oriented = tile.orientations().swap_remove(rng.index(8));
+        format!("Tile {}:\n{}", id, render::ascii(&oriented))
+    }).collect::<Vec<String>>();
+    rng.shuffle(&mut records);
+
+    Ok(Generated::new(records.join("\n"), Some(corner_product.into()), Some(roughness.into())))
+}
+
+fn monster_pixels() -> Vec<(usize, usize)> {
+    MONSTER.iter().enumerate().flat_map(|(row, line)| {
+        line.chars().enumerate().filter(|&(_, c)| c == '#').map(move |(col, _)| (row, col))
+    }).collect()
+}
+
+fn count_monsters(image: &Grid<bool>) -> usize {
+    let pixels = monster_pixels();
+    (0..=image.height() - MONSTER.len()).flat_map(|row| (0..=image.width() - MONSTER[0].len()).map(move |col| (row, col)))
+        .filter(|&(row, col)| pixels.iter().all(|&(r, c)| image[(row + r, col + c)]))
+        .count()
+}
+
+// A random image with monsters planted where their outlines don't overlap, along with how many
+// were planted, or None if more monsters than that turned up
+fn draw_image(rng: &mut Rng, side: usize) -> Option<(Grid<bool>, usize)> {
+    let len = side * INNER;
+    let (height, width) = (MONSTER.len(), MONSTER[0].len());
+    let mut image = Grid::from_fn(len, len, |_, _| rng.chance(0.25));
+
+    let mut placed: Vec<(usize, usize)> = vec![];
+    for _ in 0..side * side {
+        let (row, col) = (rng.index(len - height + 1), rng.index(len - width + 1));
+        let overlaps = placed.iter().any(|&(r, c)| row < r + height && r < row + height && col < c + width && c < col + width);
+        if overlaps || placed.len() >= side * side / 4 { continue }
+
+        monster_pixels().iter().for_each(|&(r, c)| image[(row + r, col + c)] = true);
+        placed.push((row, col));
+    }
+
+    // The monster isn't symmetric, so across all orientations only the planted ones should be found
+    let found = image.orientations().iter().map(count_monsters).sum::<usize>();
+    if found == placed.len() { Some((image, placed.len())) } else { None }
+}
+
+// Cuts `image` into tiles, in row-major order, with borders added around each one. Each border
+// between two tiles is shared by them, and every border is different from every other and from
+// itself reversed, or None is returned if that couldn't be done.
+fn cut_tiles(rng: &mut Rng, image: &Grid<bool>, side: usize) -> Option<Vec<Grid<bool>>> {
+    // A corner pixel is shared by up to four tiles
+    let corners = Grid::from_fn(side + 1, side + 1, |_, _| rng.chance(0.5));
+    let mut used: HashSet<Vec<bool>> = HashSet::new();
+    let mut border = |rng: &mut Rng, start: bool, end: bool| -> Option<Vec<bool>> {
+        for _ in 0..100 {
+            let mut pixels = vec![start];
+            pixels.extend((0..INNER).map(|_| rng.chance(0.5)));
+            pixels.push(end);
+
+            let reversed = pixels.iter().rev().copied().collect::<Vec<bool>>();
+            // A border that reads the same either way wouldn't say whether its tiles are flipped
+            if pixels != reversed && !used.contains(&pixels) && !used.contains(&reversed) {
+                used.insert(reversed);
+                used.insert(pixels.clone());
+                return Some(pixels)
+            }
+        }
+
+        None
+    };
+
+    // Across the top of each row of tiles (and the bottom of the last), left to right, and down
+    // the left of each column (and the right of the last), top to bottom
+    let mut across = vec![];
+    for row in 0..=side {
+        across.push((0..side).map(|col| border(rng, corners[(row, col)], corners[(row, col + 1)])).collect::<Option<Vec<_>>>()?);
+    }
+    let mut down = vec![];
+    for row in 0..side {
+        down.push((0..=side).map(|col| border(rng, corners[(row, col)], corners[(row + 1, col)])).collect::<Option<Vec<_>>>()?);
+    }
+
+    Some((0..side * side).map(|idx| {
+        let (row, col) = (idx / side, idx % side);
+        Grid::from_fn(TILE, TILE, |r, c| match (r, c) {
+            (0, _) => across[row][col][c],
+            (9, _) => across[row + 1][col][c],
+            (_, 0) => down[row][col][r],
+            (_, 9) => down[row][col + 1][r],
+            _ => image[(row * INNER + r - 1, col * INNER + c - 1)],
+        })
+    }).collect())
+}
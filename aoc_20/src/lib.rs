@@ -0,0 +1,330 @@
+#[macro_use] extern crate lazy_static;
+mod gen;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use util::file::{parse_subslice, FromRecord, GenericParseError, Record};
+use util::grid::Grid;
+use util::render::{self, Frames, Render, Rgb};
+use util::res::Result;
+use util::trace;
+use util::gen::{Generated, Rng};
+use util::{Answer, Error, Params, Renderer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Side {
+    Right,
+    Left,
+    Top,
+    Bottom,
+}
+
+#[derive(Clone)]
+pub struct Tile {
+    id: u64,
+    pixels: Grid<bool>,
+    edges: HashMap<Side, Vec<bool>>,
+}
+
+impl Tile {
+    fn new(id: u64, pixels: Grid<bool>) -> Tile {
+        let edges = vec![Side::Top, Side::Bottom, Side::Left, Side::Right].into_iter().map(|s| {
+            (s.clone(), get_side(&pixels, s))
+        }).collect::<HashMap<Side, Vec<bool>>>();
+
+        Tile{ id, pixels, edges }
+    }
+
+    fn rotate_left(&self) -> Tile {
+        Tile::new(self.id, self.pixels.rotate_left())
+    }
+
+    fn flip_horizontal(&self) -> Tile {
+        Tile::new(self.id, self.pixels.flip_horizontal())
+    }
+}
+
+// Edges are spelled out in counterclockwise order around the tile
+fn get_side(pixels: &Grid<bool>, side: Side) -> Vec<bool> {
+    match side {
+        Side::Left => pixels.column(0).cloned().collect(),
+        Side::Right => pixels.column(pixels.width() - 1).rev().cloned().collect(),
+        Side::Top => pixels.row(0).iter().rev().cloned().collect(),
+        Side::Bottom => pixels.row(pixels.height() - 1).to_vec(),
+    }
+}
+
+// Each tile is a record of a "Tile <id>:" header followed by 10 rows of 10 pixels
+impl FromRecord for Tile {
+    fn from_record(record: &Record) -> std::result::Result<Self, GenericParseError> {
+        let header = record.lines[0];
+        let id = match header.strip_prefix("Tile ").and_then(|rem| rem.strip_suffix(':')) {
+            Some(id_str) => parse_subslice(header, id_str).map_err(|e| record.locate(0, e))?,
+            None => return Err(record.locate(0, GenericParseError::value(format!("Invalid tile header: {}", header)))),
+        };
+
+        let pixel_rows = Record{ path: record.path, first_line: record.first_line + 1, lines: record.lines[1..].to_vec() };
+        let pixels = Grid::from_record(&pixel_rows, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        if pixels.height() != 10 || pixels.width() != 10 {
+            return Err(GenericParseError::value(format!("Tile {} is {}x{} instead of 10x10", id, pixels.height(), pixels.width())));
+        }
+
+        Ok(Tile::new(id, pixels))
+    }
+}
+
+fn flip_edge(edge: &[bool]) -> Vec<bool> {
+    edge.iter().rev().cloned().collect()
+}
+
+fn reorient_to_match(tile: &Tile, adjacent_to: Side, pixels: &[bool], flipped: bool) -> Tile {
+    let mut reorienting = tile.clone();
+    if flipped { reorienting = reorienting.flip_horizontal(); }
+
+    let matching_side = reorienting.edges.iter().find(|(_, p)| p.as_slice() == pixels).unwrap().0;
+    let desired_side = match adjacent_to {
+        Side::Top => Side::Bottom,
+        Side::Bottom => Side::Top,
+        Side::Left => Side::Right,
+        Side::Right => Side::Left,
+    };
+
+    let rotations_required = vec![Side::Right, Side::Top, Side::Left, Side::Bottom].into_iter()
+        .cycle().skip_while(|e| e != matching_side).enumerate()
+        .find(|(_, e)| *e == desired_side)
+        .unwrap().0;
+
+    (0..rotations_required).fold(reorienting, |t, _| t.rotate_left())
+}
+
+struct PuzzleSolution {
+    corner_id_product: u64,
+    raw_image: Grid<bool>,
+}
+
+fn assembled_to_raw_image(
+    assembled: &HashMap<(i64, i64), Tile>,
+    lower_left: (i64, i64),
+    upper_right: (i64, i64)
+) -> Grid<bool> {
+    let tiles_wide = (1 + upper_right.0 - lower_left.0) as usize;
+    let tiles_high = (1 + upper_right.1 - lower_left.1) as usize;
+    let mut out = Grid::new(8 * tiles_high, 8 * tiles_wide, false);
+    for (i, tile_y) in ((lower_left.1)..=(upper_right.1)).rev().enumerate() {
+        for (j, tile_x) in ((lower_left.0)..=(upper_right.0)).enumerate() {
+            let tile = assembled.get(&(tile_x, tile_y)).unwrap();
+            for pixel_row in 0usize..8 {
+                for pixel_col in 0usize..8 {
+                    let out_coords = (8 * i + pixel_row, 8 * j + pixel_col);
+                    out[out_coords] = tile.pixels[(pixel_row + 1, pixel_col + 1)];
+                }
+            }
+        }
+    }
+
+    out
+}
+
+// Draws the tiles placed so far, borders included, with a gap between neighbouring tiles
+fn draw_assembly(assembled: &HashMap<(i64, i64), Tile>) -> Grid<Option<bool>> {
+    render::rasterize(assembled.iter().flat_map(|(&(tile_x, tile_y), tile)| {
+        tile.pixels.iter().map(move |((row, col), &pixel)| {
+            ((11 * tile_x + col as i64, -11 * tile_y + row as i64), pixel)
+        })
+    }))
+}
+
+fn solve_puzzle(tiles: &HashMap<u64, Tile>, frames: &mut Frames) -> Result<PuzzleSolution> {
+    // Square side length constraint
+    let side_length = (tiles.len() as f64).sqrt().round() as i64;
+
+    // Group together tiles with common edges
+    let mut edges_to_tile_ids: HashMap<Vec<bool>, Vec<u64>> = HashMap::new();
+    for tile in tiles.values() {
+        for edge_pixels in tile.edges.values() {
+            edges_to_tile_ids.entry(edge_pixels.clone()).or_default().push(tile.id);
+        }
+    }
+
+    let origin_tile = tiles.values().next().ok_or_else(|| Error::invalid_puzzle("There are no tiles to assemble"))?;
+
+    // Assemble the image
+    let mut used: HashSet<u64> = vec![origin_tile.id].into_iter().collect();
+    let mut assembled: HashMap<(i64, i64), Tile> = vec![((0, 0), origin_tile.clone())].into_iter().collect();
+    let mut examine: VecDeque<(i64, i64)> = vec![(0, 0)].into_iter().collect();
+    let mut lower_left: (i64, i64) = (0, 0);
+    let mut upper_right: (i64, i64) = (0, 0);
+    frames.write_with(|| draw_assembly(&assembled))?;
+    while let Some(ex_coords) = examine.pop_front() {
+        let ex_tile = assembled.get(&ex_coords).unwrap().clone();
+
+        for (neighbor_offset, neighbor_side) in [
+            ((0, 1), Side::Top),
+            ((0, -1), Side::Bottom),
+            ((1, 0), Side::Right),
+            ((-1, 0), Side::Left)
+        ] {
+            let neighbor_coords = (ex_coords.0 + neighbor_offset.0, ex_coords.1 + neighbor_offset.1);
+            if assembled.contains_key(&neighbor_coords) { continue } // That spot is already filled
+
+            // Make sure this doesn't make the puzzle too big
+            let new_lower_left = (lower_left.0.min(neighbor_coords.0), lower_left.1.min(neighbor_coords.1));
+            let new_upper_right = (upper_right.0.max(neighbor_coords.0), upper_right.1.max(neighbor_coords.1));
+            if (1 + new_upper_right.0 - new_lower_left.0) > side_length { continue }
+            if (1 + new_upper_right.1 - new_lower_left.1) > side_length { continue }
+
+            // Find tile ID sharing common edge with the given neighbor side edge of this assembled tile
+            let edge_pixels = ex_tile.edges.get(&neighbor_side).unwrap();
+            let edge_pixels_rev = flip_edge(edge_pixels);
+
+            // The corresponding side on an adjacent tile should have the reverse of this edge, since
+            // we always spell out our edges in counterclockwise order on each tile
+            for (pixels, flipped) in [(edge_pixels, true), (&edge_pixels_rev, false)] {
+                let maybe_matching_tile_id = edges_to_tile_ids.get(pixels).and_then(|ids| {
+                    ids.iter().find(|&tid| {
+                        *tid != ex_tile.id && !used.contains(tid)
+                    })
+                });
+
+                match maybe_matching_tile_id {
+                    Some(other_id) if !used.contains(other_id) => {
+                        let reoriented = reorient_to_match(tiles.get(other_id).unwrap(), neighbor_side.clone(), &edge_pixels_rev, flipped);
+                        trace!(Debug, "place", tile = reoriented.id, at = neighbor_coords, next_to = ex_tile.id);
+                        used.insert(reoriented.id);
+                        assembled.insert(neighbor_coords, reoriented);
+                        examine.push_back(neighbor_coords);
+                        lower_left = new_lower_left;
+                        upper_right = new_upper_right;
+                        frames.write_with(|| draw_assembly(&assembled))?;
+                        break
+                    },
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    let (min_x, max_x, min_y, max_y) = (lower_left.0, upper_right.0, lower_left.1, upper_right.1);
+    let product = [(min_x, min_y), (min_x, max_y), (max_x, min_y), (max_x, max_y)]
+        .iter().fold(1, |acc, coord| {
+            acc * assembled.get(coord).unwrap().id
+        }
+    );
+
+    Ok(PuzzleSolution {
+        corner_id_product: product,
+        raw_image: assembled_to_raw_image(&assembled, lower_left, upper_right),
+    })
+}
+
+// Returns which # tiles are part of a sea monster, found with the sea monster kernel:
+// ..................#.
+// #....##....##....###
+// .#..#..#..#..#..#...
+fn find_sea_monsters(raw: &Grid<bool>) -> Grid<bool> {
+    lazy_static! {
+        // Vec of row/col offsets for the sea monster kernel
+        static ref KERNEL: Vec<(usize, usize)> = vec![
+            (0, 18),
+            (1, 0), (1, 5), (1, 6), (1, 11), (1, 12), (1, 17), (1, 18), (1, 19),
+            (2, 1), (2, 4), (2, 7), (2, 10), (2, 13), (2, 16),
+        ];
+    }
+
+    let mut sea_monsters = Grid::new(raw.height(), raw.width(), false);
+    for kernel_check_row_offset in 0..=(raw.height() - 3) {
+        for kernel_check_col_offset in 0..=(raw.width() - 20) {
+            let to_check = KERNEL.iter().map(|(row_off, col_off)| {
+                (kernel_check_row_offset + row_off, kernel_check_col_offset + col_off)
+            }).collect::<Vec<(usize, usize)>>();
+
+            if to_check.iter().all(|&coords| raw[coords]) {
+                // The kernel matched! Mark all these # tiles as part of a sea monster
+                to_check.iter().for_each(|&coords| sea_monsters[coords] = true);
+            }
+        }
+    }
+
+    sea_monsters
+}
+
+// A pixel of the assembled image, once sea monsters have been found in it
+#[derive(Clone, Copy)]
+enum Pixel {
+    Water,
+    Rough,
+    SeaMonster,
+}
+
+impl Render for Pixel {
+    fn glyph(&self) -> char {
+        match self {
+            Pixel::Water => '.',
+            Pixel::Rough => '#',
+            Pixel::SeaMonster => 'O',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Pixel::Water => (0, 64, 160),
+            Pixel::Rough => (200, 220, 255),
+            Pixel::SeaMonster => (0, 200, 0),
+        }
+    }
+}
+
+fn get_water_roughness(puzzle_solution: &PuzzleSolution, frames: &mut Frames) -> Result<usize> {
+    // Try every flip/rotation of the image until one has sea monsters in it
+    for image in puzzle_solution.raw_image.orientations() {
+        let sea_monsters = find_sea_monsters(&image);
+        let sea_monster_count = sea_monsters.count(|&v| v);
+        if sea_monster_count > 0 {
+            frames.write_with(|| Grid::from_fn(image.height(), image.width(), |row, col| {
+                match (image[(row, col)], sea_monsters[(row, col)]) {
+                    (_, true) => Pixel::SeaMonster,
+                    (true, false) => Pixel::Rough,
+                    (false, false) => Pixel::Water,
+                }
+            }))?;
+
+            return Ok(image.count(|&v| v) - sea_monster_count);
+        }
+    }
+
+    Err(Error::no_solution("Found no flip/rotation that matched the sea monster kernel!"))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<u64, Tile>;
+
+    fn parse(path: PathBuf) -> Result<HashMap<u64, Tile>> {
+        let tiles = util::file::read_records_to_type::<Tile>(path)?;
+        if tiles.is_empty() {
+            return Err(GenericParseError::value("Expected at least one tile").into());
+        }
+        Ok(tiles.into_iter().map(|t| (t.id, t)).collect())
+    }
+
+    // Each part assembles the image itself, so that the input holds nothing one part leaves for
+    // the other and each part's timing includes everything it does
+    fn part1(tiles: &HashMap<u64, Tile>, _params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(solve_puzzle(tiles, &mut Frames::new(renderer, "assembly"))?.corner_id_product.into())
+    }
+
+    fn part2(tiles: &HashMap<u64, Tile>, _params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        let puzzle_solution = solve_puzzle(tiles, &mut Frames::new(renderer, "assembly"))?;
+        Ok(get_water_roughness(&puzzle_solution, &mut Frames::new(renderer, "sea_monsters"))?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
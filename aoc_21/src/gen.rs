@@ -0,0 +1,79 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use util::assignment::{self, Assignment};
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const ALLERGENS: &[&str] = &["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+// Food lists built around a planted allergen for each of a few ingredients. A food only lists
+// allergens whose ingredient it has (but doesn't always list them), and safe ingredients are
+// scattered widely enough that none of them is in every food listing some allergen. Lists are
+// redrawn until the allergens can only be matched to ingredients the planted way. `size` is the
+// number of foods.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(40).max(5);
+    loop {
+        if let Some(generated) = try_generate(rng, size) {
+            return Ok(generated)
+        }
+    }
+}
+
+fn try_generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+    let mut ingredients: HashSet<String> = HashSet::new();
+    while ingredients.len() < 200 {
+        let len = rng.range(3, 7) as usize;
+        ingredients.insert((0..len).map(|_| (b'a' + rng.below(26) as u8) as char).collect());
+    }
+    let mut ingredients = ingredients.into_iter().collect::<Vec<String>>();
+    ingredients.sort();
+
+    let num_allergens = rng.range(3, 8) as usize;
+    let allergens = rng.sample(ALLERGENS, num_allergens);
+    let dangerous = rng.sample(&ingredients, allergens.len());
+    let contains = allergens.iter().cloned().zip(dangerous.iter().cloned()).collect::<BTreeMap<&str, String>>();
+    let safe = ingredients.iter().filter(|i| !dangerous.contains(i)).cloned().collect::<Vec<String>>();
+
+    let mut foods: Vec<(Vec<String>, Vec<&str>)> = vec![];
+    for _ in 0..size {
+        let num_safe = rng.range(10, 25) as usize;
+        let mut food = rng.sample(&safe, num_safe);
+        let mut listed = vec![];
+        for (allergen, ingredient) in &contains {
+            if !rng.chance(0.5) { continue }
+            food.push(ingredient.clone());
+            if rng.chance(0.6) { listed.push(*allergen); }
+        }
+
+        // Every food lists at least one allergen
+        if listed.is_empty() {
+            let (allergen, ingredient) = contains.iter().nth(rng.index(contains.len()))?;
+            if !food.contains(ingredient) { food.push(ingredient.clone()); }
+            listed.push(*allergen);
+        }
+
+        rng.shuffle(&mut food);
+        foods.push((food, listed));
+    }
+
+    // Each allergen could be in any ingredient that's in every food listing it
+    let mut candidates: HashMap<String, HashSet<String>> = HashMap::new();
+    for (food, listed) in &foods {
+        let food = food.iter().cloned().collect::<HashSet<String>>();
+        for allergen in listed {
+            let entry = candidates.entry(allergen.to_string()).or_insert_with(|| food.clone());
+            entry.retain(|i| food.contains(i));
+        }
+    }
+
+    let only_dangerous = candidates.values().flatten().all(|i| dangerous.contains(i));
+    let planted = contains.iter().map(|(a, i)| (a.to_string(), i.clone())).collect::<BTreeMap<String, String>>();
+    if candidates.len() != contains.len() || !only_dangerous || assignment::solve(&candidates) != Assignment::Unique(planted) {
+        return None
+    }
+
+    let safe_occurrences = foods.iter().map(|(food, _)| food.iter().filter(|i| !dangerous.contains(i)).count()).sum::<usize>();
+    let canonical = contains.values().cloned().collect::<Vec<String>>().join(",");
+    let input = foods.iter().map(|(food, listed)| format!("{} (contains {})\n", food.join(" "), listed.join(", "))).collect();
+    Some(Generated::new(input, Some(safe_occurrences.into()), Some(canonical.into())))
+}
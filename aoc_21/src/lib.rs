@@ -0,0 +1,95 @@
+mod gen;
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use util::assignment;
+use util::file::GenericParseError;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+pub struct FoodItem {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+impl std::str::FromStr for FoodItem {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.splitn(2, " (contains ").collect::<Vec<&str>>()[..] {
+            [part1, part2] => Ok(FoodItem{
+                ingredients: part1.split_whitespace()
+                    .map(|s| s.to_owned()).collect::<HashSet<String>>(),
+                allergens: part2.replace(&[')', ','][..], "").split_whitespace()
+                    .map(|s| s.to_owned()).collect::<HashSet<String>>(),
+            }),
+            _ => Err(GenericParseError::value(
+                format!("Invalid line: {}", s)
+            )),
+        }
+    }
+}
+
+// Maps each allergen to the set of ingredients that could contain it
+fn get_allergen_possibilities(food_items: &[FoodItem]) -> HashMap<String, HashSet<String>> {
+    let mut could_be_allergen: HashMap<String, HashSet<String>> = HashMap::new();
+    for food_item in food_items {
+        for allergen in food_item.allergens.iter() {
+            could_be_allergen.entry(allergen.clone())
+                .and_modify(|e| {
+                    *e = e.intersection(&food_item.ingredients).cloned().collect::<HashSet<String>>()
+                }).or_insert_with(|| food_item.ingredients.clone());
+        }
+    }
+
+    could_be_allergen
+}
+
+fn count_non_allergen_occurrences(food_items: &[FoodItem], could_be_allergen: &HashMap<String, HashSet<String>>) -> usize {
+    let mut all_ingredients: HashSet<String> = HashSet::new();
+    for food_item in food_items {
+        food_item.ingredients.iter().for_each(|i| { all_ingredients.insert(i.clone()); });
+    }
+
+    let mut could_not_be_allergen: HashSet<String> = all_ingredients.clone();
+    for maybe_allergens in could_be_allergen.values() {
+        for maybe_allergen in maybe_allergens.iter() {
+            could_not_be_allergen.remove(maybe_allergen);
+        }
+    }
+
+    food_items.iter().fold(0, |acc, item| {
+        acc + item.ingredients.intersection(&could_not_be_allergen).count()
+    })
+}
+
+fn get_canonical_dangerous_list(allergen_possibilities: &HashMap<String, HashSet<String>>) -> Result<String> {
+    // The solved assignment is sorted by allergen, which is the order the list needs
+    let allergens = assignment::solve(allergen_possibilities).into_unique()?;
+    Ok(allergens.into_values().collect::<Vec<String>>().join(","))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<FoodItem>;
+
+    fn parse(path: PathBuf) -> Result<Vec<FoodItem>> {
+        util::file::read_lines_to_type::<FoodItem>(path)
+    }
+
+    fn part1(food_items: &Vec<FoodItem>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let allergen_possibilities = get_allergen_possibilities(food_items);
+        Ok(count_non_allergen_occurrences(food_items, &allergen_possibilities).into())
+    }
+
+    fn part2(food_items: &Vec<FoodItem>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let allergen_possibilities = get_allergen_possibilities(food_items);
+        Ok(get_canonical_dangerous_list(&allergen_possibilities)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Some deals make a game of (non-recursive) Combat go on forever, so deals are redrawn until the
+// game ends within this many rounds
+const MAX_ROUNDS: usize = 100_000;
+
+// Shuffled decks of Combat cards, dealt evenly. Who wins, and with what deck, can only be found
+// by playing, so no answers are known. `size` is the number of cards in each deck.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(25).max(1);
+    let mut cards = (1..=2 * size as u64).collect::<Vec<u64>>();
+    loop {
+        rng.shuffle(&mut cards);
+        let (player1, player2) = cards.split_at(size);
+        if !ends(player1, player2) { continue }
+
+        let deck = |cards: &[u64]| cards.iter().map(|c| format!("{}\n", c)).collect::<String>();
+        let input = format!("Player 1:\n{}\nPlayer 2:\n{}", deck(player1), deck(player2));
+        return Ok(Generated::new(input, None, None))
+    }
+}
+
+// Whether a game of Combat with these decks ends within MAX_ROUNDS
+fn ends(player1: &[u64], player2: &[u64]) -> bool {
+    let (mut player1, mut player2) = (player1.iter().copied().collect::<VecDeque<u64>>(), player2.iter().copied().collect::<VecDeque<u64>>());
+    for _ in 0..MAX_ROUNDS {
+        let (card1, card2) = match (player1.pop_front(), player2.pop_front()) {
+            (Some(card1), Some(card2)) => (card1, card2),
+            _ => return true,
+        };
+
+        match card1 > card2 {
+            true => player1.extend([card1, card2]),
+            false => player2.extend([card2, card1]),
+        }
+    }
+
+    false
+}
@@ -0,0 +1,135 @@
+mod gen;
+
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+use util::file::{GenericParseError, Record};
+use util::res::Result;
+use util::trace;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Decks {
+    player1: VecDeque<u64>,
+    player2: VecDeque<u64>,
+}
+
+impl Decks {
+    // The input has one record per player, each starting with a "Player N:" header
+    fn from_records(records: &[Record]) -> std::result::Result<Self, GenericParseError> {
+        let deck = |record: &Record| (1..record.lines.len()).map(|idx| {
+            record.lines[idx].parse::<u64>().map_err(|e| record.locate(idx, e.into()))
+        }).collect::<std::result::Result<VecDeque<u64>, GenericParseError>>();
+
+        match records {
+            [player1, player2] => Ok(Decks{ player1: deck(player1)?, player2: deck(player2)? }),
+            _ => Err(GenericParseError::value(format!("Expected 2 decks, found {}", records.len()))),
+        }
+    }
+
+    fn get_next_cards(&mut self) -> (u64, u64) {
+        (self.player1.pop_front().unwrap(), self.player2.pop_front().unwrap())
+    }
+
+    fn move_cards_based_on_winner(&mut self, player1_card: u64, player2_card: u64, winner: Option<bool>) {
+        match winner {
+            Some(true) => { // There was a winner, and it was player 1
+                self.player1.push_back(player1_card);
+                self.player1.push_back(player2_card);
+            },
+            Some(false) => { // There was a winner, and it wasn't player 1
+                self.player2.push_back(player2_card);
+                self.player2.push_back(player1_card);
+            },
+            None => { // There was no winner
+                self.player1.push_back(player1_card);
+                self.player2.push_back(player2_card);
+            },
+        }
+    }
+
+    fn determine_winner_standard(player1_card: u64, player2_card: u64) -> Option<bool> {
+        match player1_card.cmp(&player2_card) {
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    fn standard_round(&mut self) {
+        let (player1_card, player2_card) = self.get_next_cards();
+        trace!(Debug, "round", player1 = player1_card, player2 = player2_card);
+        self.move_cards_based_on_winner(
+            player1_card, player2_card,
+            Self::determine_winner_standard(player1_card, player2_card)
+        );
+    }
+
+    fn play_game_standard(&self) -> Self {
+        let mut out = self.clone();
+        while !out.player1.is_empty() && !out.player2.is_empty() { out.standard_round(); }
+        out
+    }
+
+    // Returns the resulting decks, and true if player 1 won or false if player 2 won
+    fn play_game_recursive(&self, depth: usize) -> (Self, bool) {
+        let mut deck_memory: HashSet<Decks> = HashSet::new();
+        let mut out = self.clone();
+        trace!(Debug, "game", depth = depth, player1_cards = out.player1.len(), player2_cards = out.player2.len());
+
+        // Play the actual game
+        while !out.player1.is_empty() && !out.player2.is_empty() {
+            if deck_memory.contains(&out) { // No repeat state
+                trace!(Debug, "repeat", depth = depth);
+                return (out, true)
+            }
+            deck_memory.insert(out.clone());
+
+            let (player1_card, player2_card) = out.get_next_cards();
+            let should_recurse = player1_card as usize <= out.player1.len() && player2_card as usize <= out.player2.len();
+            trace!(Trace, "round", depth = depth, player1 = player1_card, player2 = player2_card, recurse = should_recurse);
+            out.move_cards_based_on_winner(player1_card, player2_card, if should_recurse {
+                Some(Decks{
+                    player1: out.player1.iter().take(player1_card as usize).cloned().collect::<VecDeque<u64>>(),
+                    player2: out.player2.iter().take(player2_card as usize).cloned().collect::<VecDeque<u64>>(),
+                }.play_game_recursive(depth + 1).1)
+            } else {
+                Self::determine_winner_standard(player1_card, player2_card)
+            });
+        }
+
+        let player1_won = !out.player1.is_empty();
+        trace!(Debug, "winner", depth = depth, player = if player1_won { 1 } else { 2 });
+        (out, player1_won)
+    }
+
+    fn calculate_winning_score(&self) -> u64 {
+        let winning = if !self.player1.is_empty() { &self.player1 } else { &self.player2 };
+        winning.iter().rev().enumerate().fold(0u64, |acc, (idx, val)| acc + (idx as u64 + 1) * val)
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Decks;
+
+    fn parse(path: PathBuf) -> Result<Decks> {
+        let contents = util::file::read_to_string(path.clone())?;
+        Ok(Decks::from_records(&util::file::split_records(&path, &contents))?)
+    }
+
+    fn part1(decks: &Decks, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let played = decks.play_game_standard();
+        Ok(played.calculate_winning_score().into())
+    }
+
+    fn part2(decks: &Decks, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let (played, _) = decks.play_game_recursive(0);
+        Ok(played.calculate_winning_score().into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
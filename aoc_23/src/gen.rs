@@ -0,0 +1,12 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Cup labellings, 1 to `size` (at most 9, since labels are single digits) in a random order.
+// Where the cups end up can only be found by playing, so no answers are known.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(9).clamp(5, 9);
+    let mut cups = (1..=size).collect::<Vec<usize>>();
+    rng.shuffle(&mut cups);
+    let input = cups.iter().map(|c| c.to_string()).collect::<String>() + "\n";
+    Ok(Generated::new(input, None, None))
+}
@@ -0,0 +1,112 @@
+mod gen;
+
+use std::path::PathBuf;
+use util::file::GenericParseError;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Error, ParamSpec, Params, Renderer, Solution};
+
+struct Cups {
+    curr: usize,
+    circle: Vec<usize>,
+}
+
+impl Cups {
+    fn new(order: &[usize]) -> Self {
+        let mut circle = vec![0; order.len() + 1];
+        for (&curr, &next) in order.iter().zip(order.iter().cycle().skip(1)) {
+            circle[curr] = next;
+        }
+        Cups{ curr: order[0], circle }
+    }
+
+    fn do_move(&mut self) {
+        let next1 = self.circle[self.curr];
+        let next2 = self.circle[next1];
+        let next3 = self.circle[next2];
+        let next4 = self.circle[next3];
+        let dest = (1..self.curr).rev()
+            .chain(((self.circle.len() - 4)..=(self.circle.len() - 1)).rev())
+            .find(|e| next1 != *e && next2 != *e && next3 != *e).unwrap();
+
+        self.circle[self.curr] = next4;
+        self.circle[next3] = self.circle[dest];
+        self.circle[dest] = next1;
+        self.curr = self.circle[self.curr];
+    }
+
+    fn get_n_after_val(&self, mut val: usize, n: usize) -> Vec<usize> {
+        let mut out: Vec<usize> = vec![];
+        for _ in 0..n {
+            val = self.circle[val];
+            out.push(val);
+        }
+        out
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<usize>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "part1_moves", default: "100", description: "Number of moves to make in part 1" },
+        ParamSpec{ key: "part2_moves", default: "10000000", description: "Number of moves to make in part 2" },
+        ParamSpec{ key: "part2_cups", default: "1000000", description: "Total number of cups in part 2, including the labeled ones" },
+    ];
+
+    // The labels have to be 1 to n in some order, and a move picks up three cups and needs a
+    // fourth to put them after, so there have to be at least four
+    fn parse(path: PathBuf) -> Result<Vec<usize>> {
+        let labels = util::file::read_to_string(path)?.trim().chars().map(|c| {
+            c.to_digit(10).map(|d| d as usize).ok_or_else(|| GenericParseError::value(format!("Invalid cup label: {}", c)).into())
+        }).collect::<Result<Vec<usize>>>()?;
+
+        if labels.len() < 4 {
+            return Err(GenericParseError::value(format!("Expected at least 4 cups, found {}", labels.len())).into());
+        }
+        // n different labels from 1 to n can only be all of them
+        let mut seen = vec![false; labels.len() + 1];
+        for &label in &labels {
+            if label == 0 || label > labels.len() {
+                return Err(GenericParseError::value(format!("Cup labels should be 1 to {}, found {}", labels.len(), label)).into());
+            }
+            if seen[label] {
+                return Err(GenericParseError::value(format!("Cup {} appears more than once", label)).into());
+            }
+            seen[label] = true;
+        }
+
+        Ok(labels)
+    }
+
+    fn part1(start: &Vec<usize>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let mut cups = Cups::new(start);
+        for _ in 0..params.get::<usize>("part1_moves")? {
+            cups.do_move();
+        }
+
+        let order = cups.get_n_after_val(1, start.len() - 1).iter()
+            .map(|n| n.to_string()).collect::<String>();
+        Ok(order.into())
+    }
+
+    fn part2(start: &Vec<usize>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let total_cups = params.get::<usize>("part2_cups")?;
+        if total_cups < start.len() {
+            return Err(Error::invalid_puzzle(format!("There are {} labeled cups, more than the {} cups in total", start.len(), total_cups)));
+        }
+        let start_extended = start.iter().cloned().chain((start.len() + 1)..=total_cups).collect::<Vec<usize>>();
+        let mut cups = Cups::new(&start_extended);
+        for _ in 0..params.get::<usize>("part2_moves")? {
+            cups.do_move();
+        }
+
+        let product: usize = cups.get_n_after_val(1, 2).iter().product();
+        Ok(product.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,60 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Tile-flipping directions built by picking tiles first: each tile is flipped by one to three
+// lines, each of which is a direct route to it with some back-and-forth detours mixed in. A
+// tile ends up black if an odd number of lines lead to it. How the floor changes over the days
+// can only be found by simulating it, so that answer isn't known. `size` is roughly the number of lines.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(500);
+    let mut targets: Vec<(i64, i64)> = vec![];
+    let (mut lines, mut black) = (vec![], 0);
+    while lines.len() < size {
+        // Doubled coordinates always have x + y even
+        let target = loop {
+            let (x, y) = (rng.range(-20, 20), rng.range(-10, 10));
+            if (x + y) % 2 == 0 && !targets.contains(&(x, y)) { break (x, y) }
+        };
+        targets.push(target);
+
+        let times = *rng.choose(&[1, 1, 1, 2, 3]);
+        if times % 2 == 1 { black += 1; }
+        for _ in 0..times { lines.push(route(rng, target)); }
+    }
+
+    rng.shuffle(&mut lines);
+    Ok(Generated::new(lines.concat(), Some(black.into()), None))
+}
+
+// A line of moves from the reference tile to `target`, in doubled coordinates: east and west
+// change x by 2, and the diagonal moves change both x and y by 1
+fn route(rng: &mut Rng, (x, y): (i64, i64)) -> String {
+    let mut moves = vec![];
+    // Diagonal moves get to the right row, leaning towards the target's side
+    let mut dx = x;
+    for _ in 0..y.abs() {
+        let east = dx > 0 || (dx == 0 && rng.chance(0.5));
+        moves.push(match (y > 0, east) {
+            (true, true) => "ne",
+            (true, false) => "nw",
+            (false, true) => "se",
+            (false, false) => "sw",
+        });
+        dx -= if east { 1 } else { -1 };
+    }
+    // What's left is even, since x + y is
+    for _ in 0..dx.abs() / 2 { moves.push(if dx > 0 { "e" } else { "w" }); }
+
+    for _ in 0..rng.range(0, 6) {
+        let (there, back) = match rng.below(3) {
+            0 => ("e", "w"),
+            1 => ("ne", "sw"),
+            _ => ("nw", "se"),
+        };
+        moves.push(there);
+        moves.push(back);
+    }
+
+    rng.shuffle(&mut moves);
+    moves.concat() + "\n"
+}
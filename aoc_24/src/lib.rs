@@ -0,0 +1,124 @@
+mod gen;
+
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+use util::automaton::{Automaton, BirthSurvival, HexLattice};
+use util::file::GenericParseError;
+use util::grid::Grid;
+use util::render::{self, Frames};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HexCoord {
+    x: i64, // lowest (one) bit represents half coordinates
+    y: i64, // lowest (one) bit represents half coordinates
+}
+
+impl std::str::FromStr for HexCoord {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // Points at the direction starting at byte `start`, up to and including the char at `end`
+        let e = |start: usize, end: Option<(usize, char)>| {
+            let end = end.map_or(s.len(), |(idx, c)| idx + c.len_utf8());
+            Err(GenericParseError::value_at(format!("Invalid direction: {}", &s[start..end]), start..end))
+        };
+
+        let mut coord = HexCoord{ x: 0, y: 0 };
+        let mut chars = s.char_indices().collect::<VecDeque<(usize, char)>>();
+        while let Some((idx, c)) = chars.pop_front() {
+            let offset = match c {
+                'n' => {
+                    match chars.pop_front() {
+                        Some((_, 'e')) => (1, 1),
+                        Some((_, 'w')) => (-1, 1),
+                        next => return e(idx, next),
+                    }
+                },
+                's' => {
+                    match chars.pop_front() {
+                        Some((_, 'e')) => (1, -1),
+                        Some((_, 'w')) => (-1, -1),
+                        next => return e(idx, next),
+                    }
+                },
+                'e' => (2, 0),
+                'w' => (-2, 0),
+                _ => return e(idx, Some((idx, c))),
+            };
+            coord.x += offset.0;
+            coord.y += offset.1;
+        }
+
+        Ok(coord)
+    }
+}
+
+fn get_initially_flipped(coords: &[HexCoord]) -> HashSet<HexCoord> {
+    let mut flipped: HashSet<HexCoord> = HashSet::new();
+    for coord in coords {
+        if flipped.contains(coord) {
+            flipped.remove(coord);
+        } else {
+            flipped.insert(coord.clone());
+        }
+    }
+
+    flipped
+}
+
+// Black tiles with 0 or more than 2 black neighbours flip to white, and white tiles with exactly
+// 2 black neighbours flip to black
+const FLIPPING_RULE: &str = "B2/S12";
+
+fn get_flipped_after_n_days(start_state: &HashSet<HexCoord>, n: usize, frames: &mut Frames) -> Result<usize> {
+    // HexCoord already uses the doubled coordinates HexLattice expects
+    let flipped = start_state.iter().map(|c| (c.x, c.y)).collect();
+    let mut automaton = Automaton::new(HexLattice, FLIPPING_RULE.parse::<BirthSurvival>()?, flipped);
+    frames.write_with(|| draw_floor(automaton.live()))?;
+    for _ in 0..n {
+        automaton.step();
+        frames.write_with(|| draw_floor(automaton.live()))?;
+    }
+
+    Ok(automaton.live().len())
+}
+
+// Draws every tile around the flipped ones, black tiles as # and white as ., with north at the
+// top. In doubled coordinates each row only has a tile in every other column, so the rows come
+// out staggered like the floor itself.
+fn draw_floor(flipped: &HashSet<(i64, i64)>) -> Grid<Option<bool>> {
+    let (min_x, max_x) = (flipped.iter().map(|c| c.0).min().unwrap_or(0), flipped.iter().map(|c| c.0).max().unwrap_or(0));
+    let (min_y, max_y) = (flipped.iter().map(|c| c.1).min().unwrap_or(0), flipped.iter().map(|c| c.1).max().unwrap_or(0));
+    render::rasterize((min_y..=max_y).flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+        .filter(|(x, y)| (x + y).rem_euclid(2) == 0)
+        .map(|(x, y)| ((x, -y), flipped.contains(&(x, y)))))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<HexCoord>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "days", default: "100", description: "Number of days to flip tiles for in part 2" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Vec<HexCoord>> {
+        util::file::read_lines_to_type::<HexCoord>(path)
+    }
+
+    fn part1(coords: &Vec<HexCoord>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_initially_flipped(coords).len().into())
+    }
+
+    fn part2(coords: &Vec<HexCoord>, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        let start_state = get_initially_flipped(coords);
+        Ok(get_flipped_after_n_days(&start_state, params.get("days")?, &mut Frames::new(renderer, "floor"))?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,20 @@
+use util::gen::{Generated, Rng};
+use util::math;
+use util::res::Result;
+use util::Answer;
+
+const SUBJECT_NUMBER: i128 = 7;
+const MODULUS: i128 = 20201227;
+
+// Public keys made from planted loop sizes. Both devices get to the same encryption key by
+// transforming each other's public key, which is 7 transformed by the product of the two loop
+// sizes. `size` is the largest loop size to use.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(20_000_000).max(1) as i64;
+    let (card_loop, door_loop) = (rng.range(1, size) as u128, rng.range(1, size) as u128);
+    let public_key = |loop_size: u128| math::mod_pow(SUBJECT_NUMBER, loop_size, MODULUS);
+    let encryption_key = public_key(card_loop * door_loop);
+
+    let input = format!("{}\n{}\n", public_key(card_loop), public_key(door_loop));
+    Ok(Generated::new(input, Some(encryption_key.into()), Some(Answer::Empty)))
+}
@@ -0,0 +1,64 @@
+mod gen;
+
+use std::path::PathBuf;
+use util::math;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Alternative, Answer, Error, Params, Renderer, Solution};
+
+const SUBJECT_NUMBER: i128 = 7;
+const MODULUS: i128 = 20201227;
+
+// The loop size is the discrete log of the public key, base 7
+fn get_loop_size(public_key: i128) -> Result<u128> {
+    Ok(math::discrete_log(SUBJECT_NUMBER, public_key, MODULUS)?)
+}
+
+// Finds the loop size by running the transformation one loop at a time, the way the puzzle
+// describes it. Much slower than get_loop_size for large loop sizes, but obviously correct. Values
+// repeat within MODULUS loops, so a key that hasn't turned up by then never will.
+fn brute_force_loop_size(public_key: i128) -> Result<u128> {
+    std::iter::successors(Some(1), |val| Some((val * SUBJECT_NUMBER) % MODULUS))
+        .take(MODULUS as usize)
+        .position(|val| val == public_key.rem_euclid(MODULUS))
+        .map(|loop_size| loop_size as u128)
+        .ok_or_else(|| Error::no_solution(format!("No x with {}^x ≡ {} (mod {})", SUBJECT_NUMBER, public_key, MODULUS)))
+}
+
+// The same one loop at a time transformation, for the encryption key
+fn brute_force_transform(subject_number: i128, loop_size: u128) -> i128 {
+    (0..loop_size).fold(1, |val, _| (val * subject_number) % MODULUS)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (i128, i128);
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[
+        Alternative{ name: "brute force", part: 1, run: |public_keys, _| {
+            let key1_loop_size = brute_force_loop_size(public_keys.0)?;
+            Ok(brute_force_transform(public_keys.1, key1_loop_size).into())
+        }},
+    ];
+
+    fn parse(path: PathBuf) -> Result<(i128, i128)> {
+        match util::file::read_lines_to_integers::<i128>(path)?[..] {
+            [key1, key2] => Ok((key1, key2)),
+            ref keys => Err(Error::invalid_puzzle(format!("Expected 2 public keys, found {}", keys.len()))),
+        }
+    }
+
+    fn part1(public_keys: &(i128, i128), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let key1_loop_size = get_loop_size(public_keys.0)?;
+        Ok(math::mod_pow(public_keys.1, key1_loop_size, MODULUS).into())
+    }
+
+    // Day 25 only has one puzzle
+    fn part2(_: &(i128, i128), _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(Answer::Empty)
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
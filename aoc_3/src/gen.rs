@@ -0,0 +1,29 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const WIDTH: usize = 31;
+// The default slopes: part 1 counts trees along the first, and part 2 multiplies all of them
+const SLOPES: &[(usize, usize)] = &[(3, 1), (1, 1), (5, 1), (7, 1), (1, 2)];
+
+// Maps of trees, with a tally kept of the trees placed on each slope's path as the map is
+// drawn. `size` is the number of rows.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let height = size.unwrap_or(323);
+    let density = *rng.choose(&[0.1, 0.2, 0.3]);
+    let mut hits = vec![0usize; SLOPES.len()];
+    let mut input = String::new();
+    for row in 0..height {
+        for col in 0..WIDTH {
+            let tree = rng.chance(density);
+            for (hit, &(right, down)) in hits.iter_mut().zip(SLOPES) {
+                if tree && row % down == 0 && (row / down * right) % WIDTH == col { *hit += 1; }
+            }
+
+            input.push(if tree { '#' } else { '.' });
+        }
+
+        input.push('\n');
+    }
+
+    Ok(Generated::new(input, Some(hits[0].into()), Some(hits.iter().product::<usize>().into())))
+}
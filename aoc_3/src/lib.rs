@@ -0,0 +1,71 @@
+mod gen;
+
+use std::path::PathBuf;
+use util::grid::{read_grid, Grid};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, ParamSpec, Params, Renderer, Solution};
+
+// The map repeats to the right forever, so columns past the edge wrap around
+fn count_trees_at_angle(trees: &Grid<bool>, right: usize, down: usize) -> usize {
+    (0..trees.height()).step_by(down).enumerate().filter(|&(step_count, row)| {
+        trees.get_wrapping(row as i64, (step_count * right) as i64) == Some(&true)
+    }).count()
+}
+
+// Slope is how far right and down each step goes, written `right/down` in params
+pub struct Slope {
+    right: usize,
+    down: usize,
+}
+
+impl std::str::FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let parsed = s.split_once('/').and_then(|(right, down)| {
+            Some(Slope{ right: right.parse::<usize>().ok()?, down: down.parse::<usize>().ok()? })
+        });
+
+        match parsed {
+            Some(slope) if slope.down > 0 => Ok(slope),
+            _ => Err(format!("Expected right/down with down > 0, got: {}", s)),
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<bool>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "slope", default: "3/1", description: "Slope (right/down) to count trees along in part 1" },
+        ParamSpec{ key: "slopes", default: "1/1,3/1,5/1,7/1,1/2", description: "Slopes whose tree counts are multiplied in part 2" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Grid<bool>> {
+        read_grid(path, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+
+    fn part1(trees: &Grid<bool>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let slope = params.get::<Slope>("slope")?;
+        Ok(count_trees_at_angle(trees, slope.right, slope.down).into())
+    }
+
+    fn part2(trees: &Grid<bool>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let slopes_to_check = params.get_list::<Slope>("slopes")?;
+        let product: usize = slopes_to_check.iter().fold(1, |acc, slope| {
+            acc * count_trees_at_angle(trees, slope.right, slope.down)
+        });
+
+        Ok(product.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
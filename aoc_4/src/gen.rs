@@ -0,0 +1,93 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const EYE_COLORS: &[&str] = &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+enum Kind {
+    Valid,
+    // Has every required field, but at least one has a bad value
+    BadValue,
+    // Is missing a required field
+    MissingField,
+}
+
+// Passport batches made of passports that were each built to be valid, to have a bad value, or
+// to be missing a field. `size` is the number of passports.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(250);
+    let (mut with_fields, mut valid) = (0, 0);
+    let mut passports = vec![];
+    for _ in 0..size {
+        let kind = match rng.below(3) {
+            0 => Kind::Valid,
+            1 => Kind::BadValue,
+            _ => Kind::MissingField,
+        };
+
+        let mut fields = vec![
+            ("byr", rng.range(1920, 2002).to_string()),
+            ("iyr", rng.range(2010, 2020).to_string()),
+            ("eyr", rng.range(2020, 2030).to_string()),
+            ("hgt", match rng.chance(0.5) {
+                true => format!("{}cm", rng.range(150, 193)),
+                false => format!("{}in", rng.range(59, 76)),
+            }),
+            ("hcl", format!("#{:06x}", rng.below(1 << 24))),
+            ("ecl", rng.choose(EYE_COLORS).to_string()),
+            ("pid", format!("{:09}", rng.below(1_000_000_000))),
+        ];
+
+        match kind {
+            Kind::Valid => valid += 1,
+            Kind::BadValue => {
+                let idx = rng.index(fields.len());
+                fields[idx].1 = bad_value(rng, fields[idx].0);
+            },
+            Kind::MissingField => { fields.remove(rng.index(fields.len())); },
+        }
+        if !matches!(kind, Kind::MissingField) { with_fields += 1; }
+        if rng.chance(0.5) { fields.push(("cid", rng.range(50, 350).to_string())); }
+
+        rng.shuffle(&mut fields);
+        let mut record = String::new();
+        for (idx, (key, value)) in fields.iter().enumerate() {
+            if idx > 0 { record.push(if rng.chance(0.3) { '\n' } else { ' ' }); }
+            record.push_str(&format!("{}:{}", key, value));
+        }
+        passports.push(record);
+    }
+
+    let input = passports.join("\n\n") + "\n";
+    Ok(Generated::new(input, Some(with_fields.into()), Some(valid.into())))
+}
+
+// A value that's well-formed enough to parse, but invalid for `key`
+fn bad_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => outside(rng, 1900, 1920, 2002, 2030).to_string(),
+        "iyr" => outside(rng, 1990, 2010, 2020, 2030).to_string(),
+        "eyr" => outside(rng, 2000, 2020, 2030, 2040).to_string(),
+        "hgt" => match rng.below(3) {
+            0 => format!("{}cm", outside(rng, 100, 150, 193, 250)),
+            1 => format!("{}in", outside(rng, 40, 59, 76, 99)),
+            _ => rng.range(59, 193).to_string(),
+        },
+        "hcl" => match rng.chance(0.5) {
+            true => format!("{:06x}", rng.below(1 << 24)),
+            false => format!("#{:05x}", rng.below(1 << 20)),
+        },
+        "ecl" => rng.choose(&["xry", "zzz", "lzr", "gmt", "utc"]).to_string(),
+        _ => match rng.chance(0.5) {
+            true => format!("{:08}", rng.below(100_000_000)),
+            false => format!("{:010}", rng.below(10_000_000_000)),
+        },
+    }
+}
+
+// A number in [min, lo) or (hi, max]
+fn outside(rng: &mut Rng, min: i64, lo: i64, hi: i64, max: i64) -> i64 {
+    match rng.chance(0.5) {
+        true => rng.range(min, lo - 1),
+        false => rng.range(hi + 1, max),
+    }
+}
@@ -0,0 +1,144 @@
+#[macro_use] extern crate lazy_static;
+
+mod gen;
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use util::file::{parse_subslice, FromRecord, GenericParseError, Record};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+#[derive(Clone, Copy)]
+enum HeightUnit {
+    Inch,
+    Cm,
+}
+
+enum ParsedField {
+    BirthYear(u32),
+    IssueYear(u32),
+    ExpirationYear(u32),
+    Height(Option<(u32, HeightUnit)>),
+    HairColor(String),
+    EyeColor(String),
+    PassportID(String),
+    CountryID,
+}
+
+impl ParsedField {
+    // `key` and `value` are slices of `line`, so errors can point at the right text
+    fn from_key_and_value(line: &str, key: &str, value: &str) -> std::result::Result<ParsedField, GenericParseError> {
+        match key {
+            "byr" => Ok(ParsedField::BirthYear(parse_subslice(line, value)?)),
+            "iyr" => Ok(ParsedField::IssueYear(parse_subslice(line, value)?)),
+            "eyr" => Ok(ParsedField::ExpirationYear(parse_subslice(line, value)?)),
+            "hgt" => {
+                for (unit_str, typed) in &[("in", HeightUnit::Inch), ("cm", HeightUnit::Cm)] {
+                    if let Some(num_str) = value.strip_suffix(unit_str) {
+                        return Ok(ParsedField::Height(Some((parse_subslice(line, num_str)?, *typed))));
+                    }
+                }
+
+                Ok(ParsedField::Height(None))
+            },
+            "hcl" => Ok(ParsedField::HairColor(value.to_owned())),
+            "ecl" => Ok(ParsedField::EyeColor(value.to_owned())),
+            "pid" => Ok(ParsedField::PassportID(value.to_owned())),
+            "cid" => Ok(ParsedField::CountryID),
+            _ => Err(GenericParseError::value_in(format!("Invalid passport field key: {}", key), line, key))
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        lazy_static! {
+            static ref VALID_EYE_COLORS: HashSet<&'static str> = [
+                "amb", "blu", "brn", "gry", "grn", "hzl", "oth",
+            ].iter().copied().collect::<HashSet<&'static str>>();
+        }
+
+        match self {
+            ParsedField::BirthYear(byr) => *byr >= 1920 && *byr <= 2002,
+            ParsedField::IssueYear(iyr) => *iyr >= 2010 && *iyr <= 2020,
+            ParsedField::ExpirationYear(eyr) => *eyr >= 2020 && *eyr <= 2030,
+            ParsedField::Height(Some((val, unit))) => match unit {
+                HeightUnit::Inch => *val >= 59 && *val <= 76,
+                HeightUnit::Cm => *val >= 150 && *val <= 193,
+            },
+            ParsedField::Height(None) => false,
+            ParsedField::HairColor(hcl) => {
+                hcl.len() == 7
+                && hcl.starts_with('#')
+                && hcl.chars().skip(1).all(|c| c.is_alphanumeric())
+            },
+            ParsedField::EyeColor(ecl) => VALID_EYE_COLORS.contains(ecl.as_str()),
+            ParsedField::PassportID(pid) => pid.len() == 9 && pid.parse::<u32>().is_ok(),
+            ParsedField::CountryID => true, // Ignored
+        }
+    }
+}
+
+pub struct Passport {
+    parsed_fields: HashMap<String, ParsedField>,
+}
+
+impl Passport {
+    fn is_valid(&self, keys_only: bool) -> bool {
+        lazy_static! {
+            static ref REQUIRED_FIELDS: HashSet<&'static str> = [
+                "byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid",
+            ].iter().copied().collect::<HashSet<&'static str>>();
+        };
+
+        let present_fields = self.parsed_fields.keys().map(|k| k.as_str()).collect::<HashSet<&str>>();
+        if present_fields.intersection(&REQUIRED_FIELDS).count() != REQUIRED_FIELDS.len() {
+            return false;
+        }
+        keys_only || self.parsed_fields.values().all(|pf| pf.is_valid())
+    }
+}
+
+impl FromRecord for Passport {
+    fn from_record(record: &Record) -> std::result::Result<Self, GenericParseError> {
+        let mut parsed_fields: HashMap<String, ParsedField> = HashMap::new();
+        for (idx, line) in record.lines.iter().enumerate() {
+            for kv in line.split_whitespace() {
+                let key_value_vec = kv.split(':').collect::<Vec<&str>>();
+                if key_value_vec.len() != 2 {
+                    return Err(record.locate(idx, GenericParseError::value_in(format!("Invalid passport K/V pair: {}", kv), line, kv)));
+                }
+
+                let field = ParsedField::from_key_and_value(line, key_value_vec[0], key_value_vec[1]).map_err(|e| record.locate(idx, e))?;
+                parsed_fields.insert(key_value_vec[0].to_owned(), field);
+            }
+        }
+
+        Ok(Passport{ parsed_fields })
+    }
+}
+
+fn get_num_valid_passports(passports: &[Passport], keys_only: bool) -> usize {
+    passports.iter().filter(|p| p.is_valid(keys_only)).count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Passport>;
+
+    fn parse(path: PathBuf) -> Result<Vec<Passport>> {
+        util::file::read_records_to_type::<Passport>(path)
+    }
+
+    fn part1(passports: &Vec<Passport>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_num_valid_passports(passports, true).into())
+    }
+
+    fn part2(passports: &Vec<Passport>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(get_num_valid_passports(passports, false).into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
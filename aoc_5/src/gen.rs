@@ -0,0 +1,25 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Boarding passes for a run of consecutive seat IDs with one missing from the middle, which is
+// your seat. `size` is the number of passes.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(800).clamp(3, 1023);
+    let first = rng.below(1024 - size as u64) as usize;
+    let yours = first + 1 + rng.index(size - 1);
+    let mut seats = (first..=first + size).filter(|&id| id != yours).collect::<Vec<usize>>();
+    rng.shuffle(&mut seats);
+
+    let input = seats.iter().map(|&id| format!("{}\n", boarding_pass(id))).collect();
+    Ok(Generated::new(input, Some((first + size).into()), Some(yours.into())))
+}
+
+// The row is the top 7 bits of the seat ID (F for 0 and B for 1), and the column the low 3 (L and R)
+fn boarding_pass(id: usize) -> String {
+    (0..10).rev().map(|bit| match (bit >= 3, (id >> bit) & 1 == 1) {
+        (true, false) => 'F',
+        (true, true) => 'B',
+        (false, false) => 'L',
+        (false, true) => 'R',
+    }).collect()
+}
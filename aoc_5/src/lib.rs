@@ -0,0 +1,69 @@
+mod gen;
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use util::res::Result;
+use util::file::GenericParseError;
+use util::gen::{Generated, Rng};
+use util::{Answer, Error, Params, Renderer, Solution};
+
+pub struct BoardingSeat {
+    row: u8,
+    col: u8,
+}
+
+impl std::str::FromStr for BoardingSeat {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.len() != 10 {
+            return Err(GenericParseError::value(format!("Invalid length: {}", s)));
+        }
+
+        let row_chars_and_bit_idx = s.chars().take(7).zip((0..=6).rev());
+        let col_chars_and_bit_idx = s.chars().skip(7).zip((0..=2).rev());
+        Ok(BoardingSeat{
+            row: row_chars_and_bit_idx.filter(|(c, _)| *c == 'B').fold(0, |acc, (_, idx)| acc | 1 << idx),
+            col: col_chars_and_bit_idx.filter(|(c, _)| *c == 'R').fold(0, |acc, (_, idx)| acc | 1 << idx),
+        })
+    }
+}
+
+impl BoardingSeat {
+    fn get_id(&self) -> u16 {
+        (self.row as u16) * 8 + (self.col as u16)
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<BoardingSeat>;
+
+    fn parse(path: PathBuf) -> Result<Vec<BoardingSeat>> {
+        util::file::read_lines_to_type::<BoardingSeat>(path)
+    }
+
+    fn part1(boarding_seats: &Vec<BoardingSeat>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let max_seat_id = boarding_seats.iter().map(|s| s.get_id()).max().unwrap_or(0);
+        Ok(max_seat_id.into())
+    }
+
+    fn part2(boarding_seats: &Vec<BoardingSeat>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let seat_id_set: HashSet<u16> = boarding_seats.iter().map(|s| s.get_id()).collect::<HashSet<u16>>();
+        for row in 1..=126 {
+            for col in 0..=7 {
+                let seat_id = BoardingSeat{row, col}.get_id();
+                if !seat_id_set.contains(&seat_id) && seat_id_set.contains(&(seat_id - 1)) && seat_id_set.contains(&(seat_id + 1)) {
+                    return Ok(seat_id.into());
+                }
+            }
+        }
+
+        Err(Error::no_solution("No valid missing seat ID found!"))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,44 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const QUESTIONS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+// Customs forms built group by group: at least one question everyone in the group answers,
+// plus some that only some of them do. `size` is the number of groups.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(480);
+    let questions = QUESTIONS.chars().collect::<Vec<char>>();
+    let (mut anyone, mut everyone) = (0, 0);
+    let mut groups = vec![];
+    for _ in 0..size {
+        let people = rng.range(1, 5) as usize;
+        let mut shuffled = questions.clone();
+        rng.shuffle(&mut shuffled);
+        let shared = rng.range(1, 5) as usize;
+        let some = rng.index(10);
+        let (common, partial) = shuffled[..shared + some].split_at(shared);
+
+        // Each partial question is left off at least one form, unless there's only one person
+        let mut forms = vec![common.to_vec(); people];
+        for &question in partial {
+            let skipped = rng.index(people);
+            for (idx, form) in forms.iter_mut().enumerate() {
+                if people == 1 || (idx != skipped && rng.chance(0.6)) { form.push(question); }
+            }
+        }
+
+        // A question only counts as answered by anyone if it's on some form
+        let answered = partial.iter().filter(|q| forms.iter().any(|f| f.contains(q))).count();
+        anyone += shared + answered;
+        everyone += if people == 1 { shared + answered } else { shared };
+
+        let forms = forms.into_iter().map(|mut form| {
+            rng.shuffle(&mut form);
+            form.into_iter().collect::<String>()
+        }).collect::<Vec<String>>();
+        groups.push(forms);
+    }
+
+    let input = groups.iter().map(|g| g.join("\n")).collect::<Vec<String>>().join("\n\n") + "\n";
+    Ok(Generated::new(input, Some(anyone.into()), Some(everyone.into())))
+}
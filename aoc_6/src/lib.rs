@@ -0,0 +1,51 @@
+mod gen;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use util::file::{FromRecord, GenericParseError, Record};
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+pub struct GroupAnswers {
+    num_people: usize,
+    num_yeses_by_question: HashMap<char, usize>,
+}
+
+impl FromRecord for GroupAnswers {
+    fn from_record(record: &Record) -> std::result::Result<Self, GenericParseError> {
+        let mut num_yeses_by_question: HashMap<char, usize> = HashMap::new();
+        record.lines.iter().flat_map(|line| line.chars()).for_each(|c| {
+            let people_with_yes_entry = num_yeses_by_question.entry(c).or_insert(0);
+            *people_with_yes_entry += 1;
+        });
+
+        Ok(GroupAnswers{num_people: record.lines.len(), num_yeses_by_question})
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<GroupAnswers>;
+
+    fn parse(path: PathBuf) -> Result<Vec<GroupAnswers>> {
+        util::file::read_records_to_type::<GroupAnswers>(path)
+    }
+
+    fn part1(group_answers: &Vec<GroupAnswers>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let sum = group_answers.iter().fold(0, |acc, ga| acc + ga.num_yeses_by_question.len());
+        Ok(sum.into())
+    }
+
+    fn part2(group_answers: &Vec<GroupAnswers>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let sum = group_answers.iter().fold(0, |acc, ga| {
+            acc + ga.num_yeses_by_question.iter().filter(|(_, &c)| c == ga.num_people).count()
+        });
+        Ok(sum.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
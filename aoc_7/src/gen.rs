@@ -0,0 +1,58 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const ADJECTIVES: &[&str] = &[
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+const COLORS: &[&str] = &[
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan",
+    "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+    "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise",
+    "violet", "white", "yellow",
+];
+const TARGET: &str = "shiny gold";
+// Bags only contain bags from lower tiers, which keeps the totals from growing too large
+const TIERS: usize = 8;
+
+// Bag rules where every bag is given a tier and only contains bags from lower tiers. Building
+// the rules tier by tier keeps track of which bags hold shiny gold, and how many bags each one
+// holds in total. `size` is the number of rules.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let names = ADJECTIVES.iter().flat_map(|a| COLORS.iter().map(move |c| format!("{} {}", a, c)))
+        .filter(|name| name != TARGET).collect::<Vec<String>>();
+    let size = size.unwrap_or(594).clamp(2, names.len() + 1);
+    let mut bags = rng.sample(&names, size - 1).into_iter().map(|name| (rng.index(TIERS), name)).collect::<Vec<(usize, String)>>();
+    bags.push((TIERS / 2, TARGET.to_owned()));
+    bags.sort();
+
+    // For each bag: what it holds, whether that includes shiny gold, and how many bags it holds in total
+    let mut contents: Vec<Vec<(u64, usize)>> = vec![];
+    let mut holds_target = vec![];
+    let mut totals: Vec<u64> = vec![];
+    for (tier, _) in &bags {
+        let lower = bags.iter().take_while(|(t, _)| t < tier).count();
+        let num_held = if lower == 0 || rng.chance(0.2) { 0 } else { rng.range(1, 4.min(lower as i64)) as usize };
+        let held = rng.sample(&(0..lower).collect::<Vec<usize>>(), num_held).into_iter()
+            .map(|bag| (rng.range(1, 5) as u64, bag)).collect::<Vec<(u64, usize)>>();
+
+        holds_target.push(held.iter().any(|&(_, bag)| bags[bag].1 == TARGET || holds_target[bag]));
+        totals.push(held.iter().map(|&(count, bag)| count * (1 + totals[bag])).sum());
+        contents.push(held);
+    }
+
+    let mut lines = bags.iter().zip(&contents).map(|((_, name), held)| {
+        let held = match held.is_empty() {
+            true => "no other bags".to_owned(),
+            false => held.iter().map(|&(count, bag)| {
+                format!("{} {} bag{}", count, bags[bag].1, if count == 1 { "" } else { "s" })
+            }).collect::<Vec<String>>().join(", "),
+        };
+        format!("{} bags contain {}.\n", name, held)
+    }).collect::<Vec<String>>();
+    rng.shuffle(&mut lines);
+
+    let target = bags.iter().position(|(_, name)| name == TARGET).unwrap();
+    let containers = holds_target.iter().filter(|&&h| h).count();
+    Ok(Generated::new(lines.concat(), Some(containers.into()), Some(totals[target].into())))
+}
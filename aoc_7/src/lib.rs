@@ -0,0 +1,93 @@
+#[macro_use] extern crate lazy_static;
+
+mod gen;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use regex::Regex;
+use util::res::Result;
+use util::file::{parse_subslice, GenericParseError};
+use util::graph::Graph;
+use util::gen::{Generated, Rng};
+use util::{Answer, Params, Renderer, Solution};
+
+#[derive(Clone)]
+pub struct BagRule {
+    container_spec: String,
+    contained_specs: HashMap<String, usize>,
+}
+
+impl std::str::FromStr for BagRule {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        lazy_static!{
+            static ref FULL_REGEX: Regex = Regex::new(
+                r"^([a-z]+ [a-z]+) bags contain (?:(?:no other bags)|([0-9]+ .*)).$"
+            ).unwrap();
+            static ref CONTAINED_REGEX: Regex = Regex::new(
+                r"([0-9]+)\s([a-z]+ [a-z]+) bags?"
+            ).unwrap();
+        }
+
+        let full_line_caps = FULL_REGEX.captures(s).ok_or_else(
+            || GenericParseError::value(format!("Invalid line format: {}", s))
+        )?;
+
+        let container_spec: String = full_line_caps.get(1).unwrap().as_str().to_owned();
+        let mut contained_specs: HashMap<String, usize> = HashMap::new();
+        if full_line_caps.get(2).is_some() {
+            for caps in CONTAINED_REGEX.captures_iter(s) {
+                contained_specs.insert(
+                    caps.get(2).unwrap().as_str().to_owned(),
+                    parse_subslice(s, caps.get(1).unwrap().as_str())?
+                );
+            }
+        }
+
+        Ok(BagRule{container_spec, contained_specs})
+    }
+}
+
+// Each bag has an edge to every bag it directly contains, weighted by how many it contains
+fn bag_rules_to_graph(bag_rules: &[BagRule]) -> Graph<String> {
+    let mut graph = Graph::new();
+    for bag_rule in bag_rules {
+        graph.add_node(bag_rule.container_spec.clone());
+        for (contained_spec, num_contained) in &bag_rule.contained_specs {
+            graph.add_edge(bag_rule.container_spec.clone(), contained_spec.clone(), *num_contained as u64);
+        }
+    }
+
+    graph
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<BagRule>;
+
+    fn parse(path: PathBuf) -> Result<Vec<BagRule>> {
+        util::file::read_lines_to_type::<BagRule>(path)
+    }
+
+    fn part1(bag_rules: &Vec<BagRule>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        // Anything that can reach "shiny gold" when following edges backwards could contain it
+        let containers = bag_rules_to_graph(bag_rules).reversed().reachable(&"shiny gold".to_owned());
+        let num_possible_containers = containers.len() - 1; // Exclude "shiny gold"
+        Ok(num_possible_containers.into())
+    }
+
+    fn part2(bag_rules: &Vec<BagRule>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        // Each bag holds its contents, plus everything inside each of those bags
+        let count = bag_rules_to_graph(bag_rules).aggregate(&"shiny gold".to_owned(), |_, contained| {
+            contained.iter().map(|(inside, num_contained)| num_contained * (1 + inside)).sum::<u64>()
+        })?;
+
+        Ok(count.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,62 @@
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+// Boot code laid out as:
+//     - the path run from the start, ending at a `jmp` back to the start (the corrupted instruction)
+//     - a tail that's only reached once that `jmp` becomes a `nop`, ending with a jump to the end
+//     - a trap of `jmp +0`s that loop forever
+// Every `nop` points into the trap and every `jmp` on the path skips over a `jmp +0`, so
+// swapping any instruction but the corrupted one still never terminates. The accumulator's
+// value is the sum of the path's `acc`s (part 1), plus the tail's once it's fixed (part 2).
+// `size` is roughly the number of instructions.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(600).max(10);
+    let trap_len = (size / 10).max(1);
+
+    // Arguments of `nop`s are filled in once the trap's position is known
+    let mut path: Vec<(&str, Option<i64>)> = vec![];
+    let mut path_acc = 0;
+    while path.len() < size / 2 {
+        match rng.below(4) {
+            0 => path.push(("nop", None)),
+            1 => {
+                let skipped = rng.range(1, 4);
+                path.push(("jmp", Some(skipped + 1)));
+                path.push(("jmp", Some(0)));
+                for _ in 1..skipped { path.push(("acc", Some(rng.range(-50, 50)))); }
+            },
+            _ => {
+                let value = rng.range(-50, 50);
+                path_acc += value;
+                path.push(("acc", Some(value)));
+            },
+        }
+    }
+
+    let mut tail: Vec<(&str, Option<i64>)> = vec![];
+    let mut tail_acc = 0;
+    while path.len() + 1 + tail.len() + 1 < size - trap_len {
+        match rng.below(3) {
+            0 => tail.push(("nop", None)),
+            _ => {
+                let value = rng.range(-50, 50);
+                tail_acc += value;
+                tail.push(("acc", Some(value)));
+            },
+        }
+    }
+
+    let corrupted = path.len() as i64;
+    let mut program = path;
+    program.push(("jmp", Some(-corrupted)));
+    program.extend(tail);
+    program.push(("jmp", Some(trap_len as i64 + 1)));
+    let trap_start = program.len();
+    program.extend((0..trap_len).map(|_| ("jmp", Some(0))));
+
+    let input = program.iter().enumerate().map(|(idx, &(op, arg))| {
+        let arg = arg.unwrap_or_else(|| (trap_start + rng.index(trap_len)) as i64 - idx as i64);
+        format!("{} {:+}\n", op, arg)
+    }).collect();
+    Ok(Generated::new(input, Some(path_acc.into()), Some((path_acc + tail_acc).into())))
+}
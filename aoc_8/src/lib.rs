@@ -0,0 +1,117 @@
+mod gen;
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use util::res::Result;
+use util::trace;
+use util::file::{parse_subslice, GenericParseError};
+use util::gen::{Generated, Rng};
+use util::{Answer, Error, Params, Renderer, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match &s.split_whitespace().collect::<Vec<&str>>()[..] {
+            [name, v] if v.starts_with(&['+', '-'][..]) => {
+                match *name {
+                    "nop" => Instruction::Nop(parse_subslice(s, v)?),
+                    "acc" => Instruction::Acc(parse_subslice(s, v)?),
+                    "jmp" => Instruction::Jmp(parse_subslice(s, v)?),
+                    _ => return Err(GenericParseError::value_in(format!("Invalid instruction: {}", name), s, name)),
+                }
+            },
+            [_, v] => return Err(GenericParseError::value_in(format!("Value must be signed: {}", v), s, v)),
+            _ => return Err(GenericParseError::value(format!("Invalid instruction: {}", s))),
+        })
+    }
+}
+
+// Where a jump by `offset` from `ip` lands, if that's still in the program or just past its end
+fn jump_target(ip: usize, offset: i64, len: usize) -> Option<usize> {
+    let target = ip as i64 + offset;
+    if target < 0 || target > len as i64 { None } else { Some(target as usize) }
+}
+
+fn eval_until_repeat_or_end(instructions: &[Instruction]) -> Result<(i64, bool)> {
+    let (mut ip, mut acc): (usize, i64) = (0, 0);
+    let mut seen_ips: HashSet<usize> = vec![0].into_iter().collect();
+    loop {
+        let (new_ip, new_acc) = match instructions[ip] {
+            Instruction::Nop(_) => (ip + 1, acc),
+            Instruction::Acc(v) => (ip + 1, acc + v),
+            Instruction::Jmp(v) => (jump_target(ip, v, instructions.len()).ok_or_else(|| Error::invalid_puzzle(format!(
+                "Instruction {} jumps by {} to outside the program", ip + 1, v,
+            )))?, acc),
+        };
+
+        trace!(Trace, "exec", ip = ip, instruction = instructions[ip], acc = new_acc);
+
+        // Program repeated itself
+        if !seen_ips.insert(new_ip) {
+            trace!(Debug, "loop", repeated_ip = new_ip, acc = acc);
+            return Ok((acc, false));
+        }
+
+        // Program terminated
+        if new_ip == instructions.len() {
+            trace!(Debug, "terminated", acc = new_acc);
+            return Ok((new_acc, true));
+        }
+
+        ip = new_ip;
+        acc = new_acc;
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Instruction>;
+
+    fn parse(path: PathBuf) -> Result<Vec<Instruction>> {
+        let instructions = util::file::read_lines_to_type::<Instruction>(path)?;
+        if instructions.is_empty() {
+            return Err(GenericParseError::value("Expected at least one instruction").into());
+        }
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Vec<Instruction>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let acc_value_before_first_repeat = eval_until_repeat_or_end(instructions)?;
+        Ok(acc_value_before_first_repeat.0.into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        // A `nop` that would jump out of the program as a `jmp` can't be the one to swap
+        let swaps = instructions.iter().enumerate().filter_map(|(idx, instr)| {
+            match instr {
+                Instruction::Nop(v) => jump_target(idx, *v, instructions.len()).map(|_| (idx, Instruction::Jmp(*v))),
+                Instruction::Jmp(v) => Some((idx, Instruction::Nop(*v))),
+                Instruction::Acc(_) => None,
+            }
+        });
+
+        for (idx, new_instr) in swaps {
+            trace!(Info, "swap", ip = idx, instruction = new_instr);
+            let mut modified_instructions = instructions.clone();
+            modified_instructions[idx] = new_instr;
+            if let (acc, true) = eval_until_repeat_or_end(&modified_instructions)? {
+                return Ok(acc.into());
+            }
+        }
+
+        Err(Error::no_solution("Could not perform any swaps that resulted in program termination!"))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+use util::gen::{Generated, Rng};
+use util::res::Result;
+
+const PREAMBLE: usize = 25;
+
+// XMAS data where every number after the preamble is the sum of two different numbers among the
+// 25 before it, except for one planted number. That number is the sum of exactly one contiguous
+// range of earlier numbers, and of no two earlier numbers. The other sums are always of two of the
+// smallest numbers in the window, which keeps the numbers growing about as slowly as in real
+// inputs. `size` is the number of numbers.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+    let size = size.unwrap_or(1000).max(PREAMBLE + 10);
+    loop {
+        if let Some(generated) = try_generate(rng, size) {
+            return Ok(generated)
+        }
+    }
+}
+
+fn try_generate(rng: &mut Rng, size: usize) -> Option<Generated> {
+    let mut numbers = rng.sample(&(1..=2 * PREAMBLE as u64).collect::<Vec<u64>>(), PREAMBLE);
+    let mut seen = numbers.iter().copied().collect::<HashSet<u64>>();
+    let planted_at = rng.range(size as i64 / 2, size as i64 - 1) as usize;
+    let mut planted = None;
+    while numbers.len() < size {
+        if numbers.len() == planted_at {
+            let (number, range) = plant_weakness(rng, &numbers, &seen)?;
+            planted = Some((number, range));
+            numbers.push(number);
+            seen.insert(number);
+            continue
+        }
+
+        // Retry a few times when the sum is already taken, since the numbers have to be distinct
+        let mut window = numbers[numbers.len() - PREAMBLE..].to_vec();
+        window.sort_unstable();
+        let next = (0..10).find_map(|_| {
+            let picked = rng.sample(&window[..8], 2);
+            picked[0].checked_add(picked[1]).filter(|n| !seen.contains(n))
+        })?;
+        numbers.push(next);
+        seen.insert(next);
+    }
+
+    let (number, (start, end)) = planted?;
+    let weakness = numbers[start..=end].iter().min()? + numbers[start..=end].iter().max()?;
+    let input = numbers.iter().map(|n| format!("{}\n", n)).collect();
+    Some(Generated::new(input, Some(number.into()), Some(weakness.into())))
+}
+
+// Picks a contiguous range whose sum no two numbers add up to, and that no other range of at
+// least two numbers sums to. Returns the sum and the range's first and last index.
+fn plant_weakness(rng: &mut Rng, numbers: &[u64], seen: &HashSet<u64>) -> Option<(u64, (usize, usize))> {
+    for _ in 0..100 {
+        let start = rng.index(numbers.len() - 2);
+        let end = rng.range(start as i64 + 1, (start + 16).min(numbers.len() - 1) as i64) as usize;
+        let sum = numbers[start..=end].iter().try_fold(0u64, |acc, &n| acc.checked_add(n))?;
+        if seen.contains(&sum) || seen.iter().any(|&m| m < sum && seen.contains(&(sum - m))) { continue }
+
+        let mut ranges = 0;
+        for s in 0..numbers.len() {
+            let mut total = numbers[s];
+            for &n in &numbers[s + 1..] {
+                total = total.saturating_add(n);
+                if total >= sum {
+                    if total == sum { ranges += 1; }
+                    break
+                }
+            }
+        }
+        if ranges == 1 { return Some((sum, (start, end))) }
+    }
+
+    None
+}
@@ -0,0 +1,78 @@
+mod gen;
+
+use std::cmp::Ordering;
+use std::path::PathBuf;
+use util::res::Result;
+use util::gen::{Generated, Rng};
+use util::{Answer, Error, ParamSpec, Params, Renderer, Solution};
+
+// The first number after the preamble that isn't the sum of two different entries among the
+// `preamble` numbers right before it
+fn find_unsummable(numbers: &[u64], preamble: usize) -> Result<u64> {
+    for (idx, n) in numbers.iter().enumerate().skip(preamble) {
+        let window = &numbers[(idx - preamble)..idx];
+        let summable = window.iter().enumerate().any(|(i, a)| {
+            window[(i + 1)..].iter().any(|b| a + b == *n)
+        });
+
+        if !summable {
+            return Ok(*n);
+        }
+    }
+
+    Err(Error::no_solution("All numbers could be summed to be 2 previous numbers!"))
+}
+
+fn find_weakness(numbers: &[u64], unsummable: u64) -> Result<u64> {
+    let (mut start_idx, mut end_idx, mut sum): (usize, usize, u64) = (0, 0, numbers[0]);
+    while end_idx < numbers.len() - 1 || sum > unsummable {
+        match sum.cmp(&unsummable) {
+            Ordering::Less => {
+                end_idx += 1;
+                sum += numbers[end_idx];
+            },
+            Ordering::Greater => {
+                sum -= numbers[start_idx];
+                start_idx += 1;
+                if start_idx > end_idx {
+                    end_idx = start_idx;
+                    sum = numbers[start_idx];
+                }
+            },
+            Ordering::Equal => {
+                let min_in_range = numbers[start_idx..=end_idx].iter().min().unwrap();
+                let max_in_range = numbers[start_idx..=end_idx].iter().max().unwrap();
+                return Ok(min_in_range + max_in_range);
+            }
+        }
+    }
+
+    Err(Error::no_solution(format!("Found no contiguous range adding to {}!", unsummable)))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u64>;
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec{ key: "preamble", default: "25", description: "How many preceding numbers each number must be a sum of two of" },
+    ];
+
+    fn parse(path: PathBuf) -> Result<Vec<u64>> {
+        // Generated stress-test inputs can carry `#` comment headers
+        util::file::read_data_lines(path)
+    }
+
+    fn part1(numbers: &Vec<u64>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(find_unsummable(numbers, params.get("preamble")?)?.into())
+    }
+
+    fn part2(numbers: &Vec<u64>, params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        let unsummable = find_unsummable(numbers, params.get("preamble")?)?;
+        Ok(find_weakness(numbers, unsummable)?.into())
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        gen::generate(rng, size)
+    }
+}
@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+use util::res::Result;
+use util::{Answer, Error, Params, Renderer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = String;
+
+    fn parse(path: PathBuf) -> Result<String> {
+        util::file::read_to_string(path)
+    }
+
+    fn part1(contents: &String, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Ok(contents.lines().count().into())
+    }
+
+    fn part2(_: &String, _params: &Params, _renderer: Option<&Renderer>) -> Result<Answer> {
+        Err(Error::no_solution("Part 2 not solved yet"))
+    }
+}
+
+// Paste the puzzle's example into input/example.txt, then fill in its answers here and in
+// input/answers.txt (along with the answers for input.txt once they're known)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        Day{day}::parse(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input").join("example.txt")).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day{day}::part1(&example(), &Params::defaults(Day{day}::PARAMS), None).unwrap(), Answer::from(0));
+    }
+
+    #[test]
+    #[ignore = "Part 2 not solved yet"]
+    fn part2_example() {
+        assert_eq!(Day{day}::part2(&example(), &Params::defaults(Day{day}::PARAMS), None).unwrap(), Answer::from(0));
+    }
+}
@@ -1,9 +1,5 @@
 use util::res::Result;
 
 fn main() -> Result<()> {
-    let file_path = util::file::get_input_file_path();
-    let contents = util::file::read_to_string(file_path)?;
-
-    println!("Contents: {}", contents);
-    Ok(())
-}
\ No newline at end of file
+    util::solution::run::<aoc_{day}::Day{day}>()
+}
@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+use super::file::{parse_subslice, read_data_lines, GenericParseError};
+use super::params::{Param, Params};
+use super::res::Result;
+use super::solution::DynSolution;
+
+// ExpectedAnswer is one line of a day's `input/answers.txt`, which has the form:
+//     <input file> <part> <expected answer>
+// where the input file is relative to the answers file. The input file can be followed by
+// params to run with, e.g. `example.txt[target=1000,mode=all]`, for answers that only come out
+// with something other than the defaults. Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone)]
+pub struct ExpectedAnswer {
+    pub input: String,
+    pub params: Vec<Param>,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl ExpectedAnswer {
+    // The input file and params, written the way they are in answers.txt
+    pub fn source(&self) -> String {
+        match self.params.is_empty() {
+            true => self.input.clone(),
+            false => {
+                let params = self.params.iter().map(|p| format!("{}={}", p.key, p.value)).collect::<Vec<String>>();
+                format!("{}[{}]", self.input, params.join(","))
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for ExpectedAnswer {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (source, part, answer) = match s.trim().splitn(3, ' ').collect::<Vec<&str>>()[..] {
+            [source, part, answer] => (source, part, answer),
+            _ => return Err(GenericParseError::value(format!("Invalid expected answer line: {}", s))),
+        };
+
+        let (input, params) = match source.strip_suffix(']').and_then(|s| s.split_once('[')) {
+            Some((input, params)) => (input, params.split(',').map(|p| parse_subslice(s, p)).collect::<std::result::Result<Vec<Param>, _>>()?),
+            None => (source, vec![]),
+        };
+
+        Ok(ExpectedAnswer{ input: input.to_owned(), params, part: parse_subslice(s, part)?, answer: answer.to_owned() })
+    }
+}
+
+pub fn read_expected_answers(path: PathBuf) -> Result<Vec<ExpectedAnswer>> {
+    read_data_lines(path)
+}
+
+// Runs `solution` on every input listed in `input_dir/answers.txt` (with default params, unless
+// a line gives others) and returns a description of each answer that didn't match what was
+// expected, or that couldn't be computed at all
+pub fn check_answers(solution: &dyn DynSolution, input_dir: &Path) -> Result<Vec<String>> {
+    let expected_answers = read_expected_answers(input_dir.join("answers.txt"))?;
+    let mut mismatches = vec![];
+
+    // Parse each input file once, even if it has expected answers for both parts
+    let mut inputs: Vec<&str> = vec![];
+    for expected in &expected_answers {
+        if !inputs.contains(&expected.input.as_str()) { inputs.push(&expected.input); }
+    }
+
+    for input in inputs {
+        let parsed = match solution.parse(input_dir.join(input)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                mismatches.push(format!("{}: failed to parse: {}", input, e));
+                continue
+            },
+        };
+
+        for expected in expected_answers.iter().filter(|e| e.input == input) {
+            let answer = Params::new(solution.params(), &expected.params)
+                .and_then(|params| solution.part(parsed.as_ref(), expected.part, &params, None));
+
+            match answer {
+                Ok(answer) if answer.to_string() == expected.answer => (),
+                Ok(answer) => mismatches.push(format!(
+                    "{} part {}: expected {}, got {}", expected.source(), expected.part, expected.answer, answer,
+                )),
+                Err(e) => mismatches.push(format!("{} part {}: failed: {}", expected.source(), expected.part, e)),
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
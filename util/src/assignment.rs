@@ -0,0 +1,205 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use super::res::{Error, Result};
+
+// Solves "give every key a different value out of its candidates" problems, e.g. matching
+// ticket fields to columns or allergens to ingredients.
+//
+// Keys are first assigned by elimination: a key with one candidate left gets it, and that value
+// is removed from every other key's candidates. When elimination stalls, a bipartite matching
+// checks that an assignment exists at all, and then a backtracking search looks for every
+// assignment (up to MAX_ALTERNATIVES of them) to tell whether it's unique.
+
+// The most assignments an Ambiguous result lists
+pub const MAX_ALTERNATIVES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Assignment<K: Ord, V> {
+    Unique(BTreeMap<K, V>),
+    // Every assignment that fits, or the first MAX_ALTERNATIVES found if there are more
+    Ambiguous(Vec<BTreeMap<K, V>>),
+    // Why no assignment fits
+    Impossible(String),
+}
+
+impl<K: Ord + fmt::Display, V: Ord + fmt::Display> Assignment<K, V> {
+    // The assignment if there's exactly one, or a NoSolution error describing why not
+    pub fn into_unique(self) -> Result<BTreeMap<K, V>> {
+        match self {
+            Assignment::Unique(assignment) => Ok(assignment),
+            Assignment::Impossible(reason) => Err(Error::no_solution(reason)),
+            Assignment::Ambiguous(alternatives) => {
+                // Only mention the keys that the alternatives disagree on
+                let mut options: BTreeMap<&K, BTreeSet<&V>> = BTreeMap::new();
+                for (key, value) in alternatives.iter().flatten() {
+                    options.entry(key).or_default().insert(value);
+                }
+
+                let undecided = options.iter().filter(|(_, values)| values.len() > 1).map(|(key, values)| {
+                    let values = values.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+                    format!("{} could be {}", key, values.join(" or "))
+                }).collect::<Vec<String>>();
+
+                Err(Error::no_solution(format!(
+                    "{}{} assignments fit ({})",
+                    if alternatives.len() >= MAX_ALTERNATIVES { "At least " } else { "" },
+                    alternatives.len(),
+                    undecided.join(", "),
+                )))
+            },
+        }
+    }
+}
+
+pub fn solve<K, V>(candidates: &HashMap<K, HashSet<V>>) -> Assignment<K, V>
+    where K: Clone + Ord + Hash + fmt::Display, V: Clone + Ord + Hash + fmt::Debug {
+    // Work on indices into sorted keys and values, so results don't depend on hashing order
+    let mut keys = candidates.keys().collect::<Vec<&K>>();
+    keys.sort();
+    let values = candidates.values().flatten().collect::<BTreeSet<&V>>().into_iter().collect::<Vec<&V>>();
+    let value_indices = values.iter().enumerate().map(|(idx, v)| (*v, idx)).collect::<HashMap<&V, usize>>();
+    let mut options = keys.iter().map(|k| {
+        candidates[*k].iter().map(|v| value_indices[v]).collect::<BTreeSet<usize>>()
+    }).collect::<Vec<BTreeSet<usize>>>();
+
+    let mut assigned: Vec<Option<usize>> = vec![None; keys.len()];
+    loop {
+        if let Some(k) = (0..keys.len()).find(|&k| assigned[k].is_none() && options[k].is_empty()) {
+            return Assignment::Impossible(format!("No candidates left for {}", keys[k]))
+        }
+
+        match (0..keys.len()).find(|&k| assigned[k].is_none() && options[k].len() == 1) {
+            Some(k) => {
+                let value = *options[k].iter().next().unwrap();
+                crate::trace!(Debug, "assignment.eliminate", key = keys[k].to_string(), only_candidate = values[value]);
+                assigned[k] = Some(value);
+                options.iter_mut().for_each(|o| { o.remove(&value); });
+            },
+            None => break,
+        }
+    }
+
+    let remaining = (0..keys.len()).filter(|&k| assigned[k].is_none()).collect::<Vec<usize>>();
+    crate::trace!(Info, "assignment.eliminated", eliminated = keys.len() - remaining.len(), remaining = remaining.len());
+    if !has_complete_matching(&remaining, &options, values.len()) {
+        let names = remaining.iter().map(|&k| keys[k].to_string()).collect::<Vec<String>>();
+        return Assignment::Impossible(format!("{} can't all be given different values", names.join(", ")))
+    }
+
+    let mut solutions = vec![];
+    let mut used = vec![false; values.len()];
+    search(&options, &mut assigned, &mut used, &mut solutions);
+
+    let mut solutions = solutions.into_iter().map(|solution| {
+        solution.into_iter().enumerate().map(|(k, v)| (keys[k].clone(), values[v].clone())).collect()
+    }).collect::<Vec<BTreeMap<K, V>>>();
+
+    crate::trace!(Info, "assignment.searched", solutions = solutions.len());
+    match solutions.len() {
+        1 => Assignment::Unique(solutions.pop().unwrap()),
+        _ => Assignment::Ambiguous(solutions),
+    }
+}
+
+// Whether every key in `keys` can be matched to a different one of its options (Kuhn's algorithm)
+fn has_complete_matching(keys: &[usize], options: &[BTreeSet<usize>], num_values: usize) -> bool {
+    fn augment(k: usize, options: &[BTreeSet<usize>], matched: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+        for &v in &options[k] {
+            if visited[v] { continue }
+            visited[v] = true;
+            if matched[v].is_none_or(|other| augment(other, options, matched, visited)) {
+                matched[v] = Some(k);
+                return true
+            }
+        }
+
+        false
+    }
+
+    let mut matched: Vec<Option<usize>> = vec![None; num_values];
+    keys.iter().all(|&k| augment(k, options, &mut matched, &mut vec![false; num_values]))
+}
+
+// Collects complete assignments into `solutions` until there are MAX_ALTERNATIVES of them,
+// always branching on the unassigned key with the fewest options left
+fn search(options: &[BTreeSet<usize>], assigned: &mut Vec<Option<usize>>, used: &mut Vec<bool>, solutions: &mut Vec<Vec<usize>>) {
+    if solutions.len() >= MAX_ALTERNATIVES { return }
+
+    let next = (0..assigned.len()).filter(|&k| assigned[k].is_none())
+        .min_by_key(|&k| options[k].iter().filter(|&&v| !used[v]).count());
+
+    match next {
+        None => solutions.push(assigned.iter().map(|v| v.unwrap()).collect()),
+        Some(k) => {
+            for &v in &options[k] {
+                if used[v] { continue }
+                assigned[k] = Some(v);
+                used[v] = true;
+                search(options, assigned, used, solutions);
+                used[v] = false;
+                assigned[k] = None;
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(options: &[(&'static str, &[u32])]) -> HashMap<&'static str, HashSet<u32>> {
+        options.iter().map(|&(key, values)| (key, values.iter().copied().collect())).collect()
+    }
+
+    fn assignment(pairs: &[(&'static str, u32)]) -> BTreeMap<&'static str, u32> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn unique_by_elimination() {
+        let solved = solve(&candidates(&[("a", &[1, 2, 3]), ("b", &[1, 2]), ("c", &[1])]));
+        assert_eq!(solved, Assignment::Unique(assignment(&[("a", 3), ("b", 2), ("c", 1)])));
+    }
+
+    // A unique assignment always leaves some key with one candidate, so elimination alone finds
+    // it. Once elimination stalls, the search decides between ambiguous and impossible.
+    #[test]
+    fn stalled_elimination_falls_back_to_search() {
+        let solved = solve(&candidates(&[("a", &[1]), ("b", &[2, 3]), ("c", &[2, 3]), ("d", &[1, 4])]));
+        assert_eq!(solved, Assignment::Ambiguous(vec![
+            assignment(&[("a", 1), ("b", 2), ("c", 3), ("d", 4)]),
+            assignment(&[("a", 1), ("b", 3), ("c", 2), ("d", 4)]),
+        ]));
+        assert_eq!(solved.into_unique().unwrap_err().to_string(), "No solution: 2 assignments fit (b could be 2 or 3, c could be 2 or 3)");
+    }
+
+    #[test]
+    fn ambiguous_results_are_capped() {
+        let all = [1, 2, 3, 4, 5];
+        let solved = solve(&candidates(&[("a", &all), ("b", &all), ("c", &all), ("d", &all), ("e", &all)]));
+        match &solved {
+            Assignment::Ambiguous(alternatives) => {
+                assert_eq!(alternatives.len(), MAX_ALTERNATIVES);
+                for alternative in alternatives {
+                    assert_eq!(alternative.values().collect::<BTreeSet<&u32>>().len(), all.len(), "{:?}", alternative);
+                }
+            },
+            other => panic!("Expected an ambiguous assignment, got {:?}", other),
+        }
+        assert!(solved.into_unique().unwrap_err().to_string().contains("At least 10 assignments fit"));
+    }
+
+    #[test]
+    fn impossible_when_a_key_runs_out_of_candidates() {
+        let solved = solve(&candidates(&[("a", &[1]), ("b", &[1])]));
+        assert_eq!(solved, Assignment::Impossible("No candidates left for b".to_owned()));
+    }
+
+    #[test]
+    fn impossible_when_no_matching_exists() {
+        // Elimination stalls straight away, and three keys can't share two values
+        let solved = solve(&candidates(&[("a", &[1, 2]), ("b", &[1, 2]), ("c", &[1, 2]), ("d", &[3, 4])]));
+        assert_eq!(solved, Assignment::Impossible("a, b, c, d can't all be given different values".to_owned()));
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use super::file::GenericParseError;
+use super::grid::Grid;
+
+// Topology decides which cells exist and which cells neighbour each other
+pub trait Topology {
+    type Cell: Clone + Eq + Hash;
+
+    fn neighbours<'a>(&'a self, cell: &'a Self::Cell) -> Box<dyn Iterator<Item = Self::Cell> + 'a>;
+
+    // Every cell, for finite topologies. Infinite ones return None, and then only live cells and
+    // their neighbours are considered each generation, so their rules mustn't birth cells that
+    // have no live neighbours (B0).
+    fn cells(&self) -> Option<Box<dyn Iterator<Item = Self::Cell> + '_>> {
+        None
+    }
+}
+
+// FiniteTopology is an explicit set of cells, each with its own list of neighbours. It can
+// express any neighbourhood, e.g. the seats visible from each seat in a grid.
+pub struct FiniteTopology<C> {
+    neighbours: HashMap<C, Vec<C>>,
+}
+
+impl<C: Clone + Eq + Hash> FiniteTopology<C> {
+    pub fn new(neighbours: HashMap<C, Vec<C>>) -> Self {
+        FiniteTopology{ neighbours }
+    }
+}
+
+impl FiniteTopology<(usize, usize)> {
+    // The cells of `grid` for which `include` is true, with neighbours chosen by `neighbours`
+    // (e.g. Grid::neighbours_8). Neighbours that aren't included cells are dropped.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        include: impl Fn(&T) -> bool,
+        neighbours: impl Fn(&Grid<T>, (usize, usize)) -> Vec<(usize, usize)>,
+    ) -> Self {
+        FiniteTopology{
+            neighbours: grid.iter().filter(|(_, t)| include(t)).map(|(pos, _)| {
+                (pos, neighbours(grid, pos).into_iter().filter(|&n| include(&grid[n])).collect())
+            }).collect(),
+        }
+    }
+}
+
+impl<C: Clone + Eq + Hash> Topology for FiniteTopology<C> {
+    type Cell = C;
+
+    fn neighbours<'a>(&'a self, cell: &'a C) -> Box<dyn Iterator<Item = C> + 'a> {
+        Box::new(self.neighbours.get(cell).into_iter().flatten().cloned())
+    }
+
+    fn cells(&self) -> Option<Box<dyn Iterator<Item = C> + '_>> {
+        Some(Box::new(self.neighbours.keys().cloned()))
+    }
+}
+
+// Lattice is an infinite N-dimensional integer lattice where each cell neighbours the 3^N - 1
+// cells that differ from it by at most 1 along every axis
+pub struct Lattice<const N: usize> {
+    offsets: Vec<[i64; N]>,
+}
+
+impl<const N: usize> Lattice<N> {
+    pub fn new() -> Self {
+        let mut offsets: Vec<[i64; N]> = vec![[0; N]];
+        for axis in 0..N {
+            offsets = offsets.into_iter().flat_map(|incomplete| (-1..=1).map(move |offset| {
+                let mut cloned = incomplete;
+                cloned[axis] = offset;
+                cloned
+            })).collect();
+        }
+        offsets.retain(|o| *o != [0; N]);
+
+        Lattice{ offsets }
+    }
+}
+
+impl<const N: usize> Default for Lattice<N> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<const N: usize> Topology for Lattice<N> {
+    type Cell = [i64; N];
+
+    fn neighbours<'a>(&'a self, cell: &'a [i64; N]) -> Box<dyn Iterator<Item = [i64; N]> + 'a> {
+        Box::new(self.offsets.iter().map(move |o| {
+            let mut out = *cell;
+            out.iter_mut().zip(o.iter()).for_each(|(c, o)| *c += o);
+            out
+        }))
+    }
+}
+
+// HexLattice is an infinite grid of hexagons with east/west neighbours, in "doubled" (x, y)
+// coordinates: moving east or west changes x by 2, and moving diagonally changes x and y by 1
+pub struct HexLattice;
+
+impl HexLattice {
+    // e, w, ne, nw, se, sw
+    const OFFSETS: [(i64, i64); 6] = [(2, 0), (-2, 0), (1, 1), (-1, 1), (1, -1), (-1, -1)];
+}
+
+impl Topology for HexLattice {
+    type Cell = (i64, i64);
+
+    fn neighbours<'a>(&'a self, &(x, y): &'a (i64, i64)) -> Box<dyn Iterator<Item = (i64, i64)> + 'a> {
+        Box::new(Self::OFFSETS.iter().map(move |(dx, dy)| (x + dx, y + dy)))
+    }
+}
+
+// Rule decides whether a cell is alive in the next generation
+pub trait Rule {
+    fn next_state(&self, alive: bool, live_neighbours: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next_state(&self, alive: bool, live_neighbours: usize) -> bool {
+        self(alive, live_neighbours)
+    }
+}
+
+// BirthSurvival is a rule in B/S notation, e.g. "B3/S23" for Conway's Life: dead cells with
+// exactly 3 live neighbours are born, and live cells with 2 or 3 live neighbours survive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BirthSurvival {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule for BirthSurvival {
+    fn next_state(&self, alive: bool, live_neighbours: usize) -> bool {
+        if alive { self.survival.contains(&live_neighbours) } else { self.birth.contains(&live_neighbours) }
+    }
+}
+
+impl std::str::FromStr for BirthSurvival {
+    type Err = GenericParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let counts = |part: &str, prefix: char| -> std::result::Result<Vec<usize>, GenericParseError> {
+            let digits = part.strip_prefix(prefix)
+                .ok_or_else(|| GenericParseError::value_in(format!("Expected {}<counts>", prefix), s, part))?;
+            digits.char_indices().map(|(idx, c)| c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                GenericParseError::value_in(format!("Invalid neighbour count: {}", c), s, &digits[idx..(idx + c.len_utf8())])
+            })).collect()
+        };
+
+        match s.split_once('/') {
+            Some((birth, survival)) => Ok(BirthSurvival{ birth: counts(birth, 'B')?, survival: counts(survival, 'S')? }),
+            None => Err(GenericParseError::value(format!("Expected B<counts>/S<counts>, got: {}", s))),
+        }
+    }
+}
+
+// Cycle describes a state that repeats: the state first seen at generation `start` comes back
+// every `period` generations. A stable state is a cycle with a period of 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+// Earlier generations' live cells, grouped by fingerprint, along with the generation each was seen at
+type SeenStates<C> = HashMap<u64, Vec<(usize, HashSet<C>)>>;
+
+// Automaton holds the live cells of a topology and steps them forward one generation at a time
+pub struct Automaton<T: Topology, R: Rule> {
+    topology: T,
+    rule: R,
+    live: HashSet<T::Cell>,
+    generation: usize,
+}
+
+impl<T: Topology, R: Rule> Automaton<T, R> {
+    pub fn new(topology: T, rule: R, live: HashSet<T::Cell>) -> Self {
+        Automaton{ topology, rule, live, generation: 0 }
+    }
+
+    pub fn live(&self) -> &HashSet<T::Cell> { &self.live }
+    pub fn generation(&self) -> usize { self.generation }
+
+    // Advances one generation, returning whether any cell changed
+    pub fn step(&mut self) -> bool {
+        let mut live_neighbours: HashMap<T::Cell, usize> = HashMap::new();
+        for cell in &self.live {
+            for neighbour in self.topology.neighbours(cell) {
+                *live_neighbours.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        // Cells that are dead with no live neighbours only matter if they can be born from nothing,
+        // which is only allowed in finite topologies
+        let candidates: HashSet<T::Cell> = match self.topology.cells() {
+            Some(cells) => cells.collect(),
+            None => self.live.iter().cloned().chain(live_neighbours.keys().cloned()).collect(),
+        };
+
+        let next = candidates.into_iter().filter(|cell| {
+            self.rule.next_state(self.live.contains(cell), *live_neighbours.get(cell).unwrap_or(&0))
+        }).collect::<HashSet<T::Cell>>();
+
+        let changed = next != self.live;
+        self.live = next;
+        self.generation += 1;
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) -> &HashSet<T::Cell> {
+        (0..generations).for_each(|_| { self.step(); });
+        &self.live
+    }
+
+    // Runs until a generation changes nothing. This never returns if the automaton oscillates
+    // instead of settling down, so use find_cycle if that's possible.
+    pub fn run_until_stable(&mut self) -> &HashSet<T::Cell> {
+        while self.step() {}
+        &self.live
+    }
+
+    // Runs until the live cells repeat a previous generation's, for at most `max_generations`.
+    // Every generation's state is kept, so this is meant for exploring small automata.
+    pub fn find_cycle(&mut self, max_generations: usize) -> Option<Cycle> {
+        let mut seen: SeenStates<T::Cell> = HashMap::new();
+        for _ in 0..=max_generations {
+            let fingerprint = fingerprint(&self.live);
+            let same_fingerprint = seen.entry(fingerprint).or_default();
+            if let Some((start, _)) = same_fingerprint.iter().find(|(_, state)| *state == self.live) {
+                return Some(Cycle{ start: *start, period: self.generation - start })
+            }
+
+            same_fingerprint.push((self.generation, self.live.clone()));
+            self.step();
+        }
+
+        None
+    }
+}
+
+// Combines the hashes of every cell in an order-independent way, so equal sets hash the same
+fn fingerprint<C: Hash>(cells: &HashSet<C>) -> u64 {
+    cells.iter().fold(cells.len() as u64, |acc, cell| {
+        let mut hasher = DefaultHasher::new();
+        cell.hash(&mut hasher);
+        acc.wrapping_add(hasher.finish())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(live: &[[i64; 2]]) -> Automaton<Lattice<2>, BirthSurvival> {
+        Automaton::new(Lattice::new(), "B3/S23".parse().unwrap(), live.iter().copied().collect())
+    }
+
+    #[test]
+    fn blinker_has_period_two() {
+        let mut blinker = life(&[[0, -1], [0, 0], [0, 1]]);
+        assert_eq!(blinker.find_cycle(10), Some(Cycle{ start: 0, period: 2 }));
+
+        // Half a period on, it's lying the other way
+        let mut blinker = life(&[[0, -1], [0, 0], [0, 1]]);
+        assert_eq!(blinker.run(1), &[[-1, 0], [0, 0], [1, 0]].iter().copied().collect());
+        assert_eq!(life(&[[0, -1], [0, 0], [0, 1]]).find_cycle(1), None);
+    }
+
+    #[test]
+    fn still_life_stabilises() {
+        // Three cells of a block fill in the fourth, and then nothing changes
+        let block = [[0, 0], [0, 1], [1, 0], [1, 1]].iter().copied().collect::<HashSet<[i64; 2]>>();
+        let mut automaton = life(&[[0, 0], [0, 1], [1, 0]]);
+        assert_eq!(automaton.run_until_stable(), &block);
+        assert_eq!(automaton.generation(), 2);
+
+        assert_eq!(life(&[[0, 0], [0, 1], [1, 0]]).find_cycle(10), Some(Cycle{ start: 1, period: 1 }));
+    }
+
+    #[test]
+    fn parses_birth_survival_rules() {
+        assert_eq!("B36/S23".parse::<BirthSurvival>().unwrap(), BirthSurvival{ birth: vec![3, 6], survival: vec![2, 3] });
+        assert_eq!("B/S".parse::<BirthSurvival>().unwrap(), BirthSurvival{ birth: vec![], survival: vec![] });
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        for rule in ["B3S23", "3/S23", "B3/23", "S23/B3", "b3/s23", "B3/S2x", "B3/S2/S3", ""] {
+            assert!(rule.parse::<BirthSurvival>().is_err(), "{:?} should be rejected", rule);
+        }
+
+        // Errors point at the offending part of the rule
+        assert_eq!("B3/S2x".parse::<BirthSurvival>().unwrap_err().span(), Some(5..6));
+        assert_eq!("B3/23".parse::<BirthSurvival>().unwrap_err().span(), Some(3..5));
+    }
+}
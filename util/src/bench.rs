@@ -0,0 +1,137 @@
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use super::file::Cli;
+use super::params::Params;
+use super::render::Renderer;
+use super::res::{Context, Error, Result};
+use super::solution::DynSolution;
+
+// A stage whose median time grows by more than this fraction against the baseline is flagged
+const REGRESSION_THRESHOLD: f64 = 0.1;
+
+// Timing holds the spread of times (in nanoseconds) one stage took over all repetitions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timing{
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: samples[samples.len() / 2].as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+        }
+    }
+}
+
+// BenchReport is what gets written with `--bench-json`. Parts that weren't run are left out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub input: String,
+    pub repetitions: usize,
+    pub parse: Timing,
+    pub part1: Option<Timing>,
+    pub part2: Option<Timing>,
+}
+
+impl BenchReport {
+    fn stages(&self) -> Vec<(&'static str, &Timing)> {
+        let mut out = vec![("parse", &self.parse)];
+        if let Some(t) = &self.part1 { out.push(("part 1", t)); }
+        if let Some(t) = &self.part2 { out.push(("part 2", t)); }
+        out
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let start = Instant::now();
+    let out = f()?;
+    Ok((out, start.elapsed()))
+}
+
+// Parses the input and solves the given part (or both parts) `repetitions` times, timing each stage separately
+pub fn bench(
+    solution: &dyn DynSolution, path: PathBuf, part: Option<u8>, params: &Params, renderer: Option<&Renderer>, repetitions: usize,
+) -> Result<BenchReport> {
+    if repetitions == 0 {
+        return Err(Error::usage("Benchmarks need at least 1 repetition"));
+    }
+
+    let parts: Vec<u8> = [1, 2].iter().cloned().filter(|&p| part.is_none() || part == Some(p)).collect();
+    let mut parse_samples = vec![];
+    let mut part_samples: Vec<Vec<Duration>> = vec![vec![], vec![]];
+    for _ in 0..repetitions {
+        let (input, elapsed) = time(|| solution.parse(path.clone()))?;
+        parse_samples.push(elapsed);
+
+        for &p in &parts {
+            let (_, elapsed) = time(|| solution.part(input.as_ref(), p, params, renderer))?;
+            part_samples[p as usize - 1].push(elapsed);
+        }
+    }
+
+    let mut part_timings = part_samples.into_iter()
+        .map(|samples| if samples.is_empty() { None } else { Some(Timing::from_samples(samples)) });
+
+    Ok(BenchReport{
+        input: path.display().to_string(),
+        repetitions,
+        parse: Timing::from_samples(parse_samples),
+        part1: part_timings.next().unwrap(),
+        part2: part_timings.next().unwrap(),
+    })
+}
+
+// Returns a line for each stage in both reports comparing median times, flagging regressions
+pub fn compare(baseline: &BenchReport, current: &BenchReport) -> Vec<String> {
+    current.stages().into_iter().filter_map(|(name, curr)| {
+        let (_, base) = baseline.stages().into_iter().find(|(n, _)| *n == name)?;
+        let change = (curr.median_ns as f64 - base.median_ns as f64) / base.median_ns.max(1) as f64;
+        Some(format!(
+            "{}: {:?} -> {:?} ({:+.1}%){}",
+            name,
+            Duration::from_nanos(base.median_ns),
+            Duration::from_nanos(curr.median_ns),
+            change * 100.0,
+            if change > REGRESSION_THRESHOLD { " REGRESSION" } else { "" },
+        ))
+    }).collect()
+}
+
+// Runs a benchmark as configured by the command line, printing the timings and writing or
+// comparing against JSON reports if asked to
+pub fn run_bench(solution: &dyn DynSolution, path: PathBuf, params: &Params, repetitions: usize, cli: &Cli) -> Result<()> {
+    let report = bench(solution, path, cli.part, params, cli.renderer().as_ref(), repetitions)?;
+
+    println!("[Bench] {} repetitions on {}", report.repetitions, report.input);
+    for (name, timing) in report.stages() {
+        println!(
+            "{}: min {:?}, median {:?}, max {:?}",
+            name,
+            Duration::from_nanos(timing.min_ns),
+            Duration::from_nanos(timing.median_ns),
+            Duration::from_nanos(timing.max_ns),
+        );
+    }
+
+    if let Some(baseline_path) = &cli.bench_baseline {
+        let context = || format!("Reading bench baseline {}", baseline_path.display());
+        let baseline: BenchReport = serde_json::from_reader(File::open(baseline_path).with_context(context)?)
+            .with_context(context)?;
+        println!("[Bench] Compared to {}", baseline_path.display());
+        compare(&baseline, &report).iter().for_each(|line| println!("{}", line));
+    }
+
+    if let Some(json_path) = &cli.bench_json {
+        let context = || format!("Writing bench report {}", json_path.display());
+        serde_json::to_writer_pretty(File::create(json_path).with_context(context)?, &report).with_context(context)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+use super::gen::Rng;
+use super::params::Params;
+use super::res::{Context, Result};
+use super::solution::DynSolution;
+
+// Runs a day's alternatives (see Solution::ALTERNATIVES) on inputs from its generator, one for
+// each seed in `seeds`, and returns a description of every answer an alternative disagreed with
+// its part on. Inputs are written to `dir` to be parsed, and only kept if there was a disagreement.
+pub fn check_alternatives(solution: &dyn DynSolution, seeds: Range<u64>, size: Option<usize>, dir: &Path) -> Result<Vec<String>> {
+    let params = Params::defaults(solution.params());
+    fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+
+    let mut disagreements = vec![];
+    for seed in seeds {
+        let generated = solution.generate(&mut Rng::new(seed), size).with_context(|| format!("Seed {}", seed))?;
+        let path = dir.join(format!("gen_{}.txt", seed));
+        fs::write(&path, &generated.input).with_context(|| format!("Writing {}", path.display()))?;
+
+        let input = solution.parse(path.clone()).with_context(|| format!("Seed {}", seed))?;
+        let found = solution.compare_alternatives(input.as_ref(), &params)?;
+        match found.is_empty() {
+            true => fs::remove_file(&path).with_context(|| format!("Removing {}", path.display()))?,
+            false => disagreements.extend(found.into_iter().map(|d| format!("{}: {}", path.display(), d))),
+        }
+    }
+
+    Ok(disagreements)
+}
@@ -1,78 +1,373 @@
 use std::fmt;
 use std::fs::File;
+use std::marker::PhantomData;
 use std::io::{prelude::*, BufReader};
-use std::path::PathBuf;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::str::FromStr;
 use structopt::StructOpt;
-use super::res::Result;
+use super::params::Param;
+use super::render::{Format, Renderer};
+use super::report;
+use super::res::{Context, Result};
 
+// Cli holds the options shared by every day's binary. The runner flattens it into its own
+// subcommands, so it only contains options that make sense for any day.
 #[derive(Debug, StructOpt)]
-struct Cli {
-    #[structopt(short = "f", parse(from_os_str))]
-    file: PathBuf,
+pub struct Cli {
+    /// Input file to solve, instead of the day's input/input.txt. Use `-` to read from stdin,
+    /// or repeat to solve several files in one go
+    #[structopt(short = "f", long = "input", parse(from_os_str), number_of_values = 1)]
+    pub files: Vec<PathBuf>,
+
+    /// Only solves the given part, instead of both
+    #[structopt(short = "p", long = "part", possible_values = &["1", "2"])]
+    pub part: Option<u8>,
+
+    /// Overrides one of the day's puzzle constants, e.g. `--param target=2021`. Can be repeated.
+    /// Run `aoc params <day>` to list them with their defaults.
+    #[structopt(long = "param", number_of_values = 1)]
+    pub params: Vec<Param>,
+
+    /// Times parsing and each part over this many repetitions, instead of printing answers
+    #[structopt(long = "bench")]
+    pub bench: Option<usize>,
+
+    /// Writes the benchmark timings to this file as JSON
+    #[structopt(long = "bench-json", parse(from_os_str), requires = "bench")]
+    pub bench_json: Option<PathBuf>,
+
+    /// Compares the benchmark timings against a JSON file written by a previous run
+    #[structopt(long = "bench-baseline", parse(from_os_str), requires = "bench")]
+    pub bench_baseline: Option<PathBuf>,
+
+    /// Writes frames showing the puzzle's state into this directory, for days that can draw it
+    /// (11, 17, 20 and 24)
+    #[structopt(long = "render", parse(from_os_str))]
+    pub render: Option<PathBuf>,
+
+    /// The format to render frames in: ascii (the default), pbm or ppm
+    #[structopt(long = "render-format", requires = "render")]
+    pub render_format: Option<Format>,
+
+    /// Writes the steps the solution takes to stderr. Repeat for more detail (up to -vvv)
+    #[structopt(short = "v", long = "trace", parse(from_occurrences))]
+    pub trace: u8,
+
+    /// Prints how each line of the input was judged, as text or json, instead of the answers
+    /// (for days that have a report: 2)
+    #[structopt(long = "report", conflicts_with = "bench")]
+    pub report: Option<report::Format>,
 }
 
-pub fn get_input_file_path() -> PathBuf {
-    let opt = Cli::from_args();
-    opt.file
+impl Cli {
+    // Returns the input files given on the command line, or just `default` if there weren't any
+    pub fn input_file_paths(&self, default: PathBuf) -> Vec<PathBuf> {
+        if self.files.is_empty() { vec![default] } else { self.files.clone() }
+    }
+
+    pub fn renderer(&self) -> Option<Renderer> {
+        self.render.as_ref().map(|dir| Renderer{ dir: dir.clone(), format: self.render_format.unwrap_or(Format::Ascii) })
+    }
+}
+
+// Passing this as an input path reads stdin instead of a file
+pub const STDIN_PATH: &str = "-";
+
+// Stdin can only be read once, so it's buffered in full the first time it's opened, and every
+// later open (e.g. each benchmark repetition) reads that same buffer
+fn stdin_contents() -> Result<&'static str> {
+    static STDIN: OnceLock<String> = OnceLock::new();
+    if STDIN.get().is_none() {
+        let mut contents = String::new();
+        std::io::stdin().read_to_string(&mut contents)?;
+        let _ = STDIN.set(contents);
+    }
+
+    Ok(STDIN.get().unwrap())
+}
+
+// Opens `path` for reading, or stdin if it's STDIN_PATH. All of the readers below go through this.
+pub fn open(path: &Path) -> Result<Box<dyn BufRead>> {
+    if path == Path::new(STDIN_PATH) {
+        Ok(Box::new(std::io::Cursor::new(stdin_contents()?.as_bytes())))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path).with_context(|| format!("Opening {}", path.display()))?)))
+    }
 }
 
 pub fn read_to_string(path: PathBuf) -> Result<String> {
-    let f = File::open(path)?;
-    let mut reader = BufReader::new(f);
+    let mut reader = open(&path)?;
     let mut contents = String::new();
     reader.read_to_string(&mut contents)?;
 
     Ok(contents)
 }
 
-#[derive(Debug)]
+// Location is where in an input file a parse error happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: usize, // 1-based
+    pub text: String, // The full text of the line
+}
+
 pub enum GenericParseError {
-    LineError,
-    ValueError(String),
+    // The line couldn't be read from the file at all
+    LineError(String),
+    // The line's contents were invalid. `span` is the byte range of the offending text
+    // within the line, or None if the line as a whole is at fault.
+    ValueError { message: String, span: Option<Range<usize>> },
+    // One of the errors above, along with where it happened
+    Located(Box<GenericParseError>, Location),
 }
 
+impl GenericParseError {
+    // A value error blaming the whole line
+    pub fn value<S: Into<String>>(message: S) -> Self {
+        GenericParseError::ValueError{ message: message.into(), span: None }
+    }
+
+    // A value error blaming the bytes in `span` within the line
+    pub fn value_at<S: Into<String>>(message: S, span: Range<usize>) -> Self {
+        GenericParseError::ValueError{ message: message.into(), span: Some(span) }
+    }
+
+    // A value error blaming `sub`, which must be a slice of the line `s`
+    pub fn value_in<S: Into<String>>(message: S, s: &str, sub: &str) -> Self {
+        Self::value_at(message, subslice_span(s, sub))
+    }
+
+    // Attaches the file and line this error happened on, unless it already has a location
+    pub fn locate(self, path: &Path, line: usize, text: &str) -> Self {
+        match self {
+            GenericParseError::Located(..) => self,
+            e => GenericParseError::Located(Box::new(e), Location{
+                path: path.to_owned(),
+                line,
+                text: text.to_owned(),
+            }),
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            GenericParseError::Located(_, location) => Some(location),
+            _ => None,
+        }
+    }
+
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            GenericParseError::LineError(_) => None,
+            GenericParseError::ValueError{ span, .. } => span.clone(),
+            GenericParseError::Located(e, _) => e.span(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            GenericParseError::LineError(m) => format!("Could not successfully read line: {}", m),
+            GenericParseError::ValueError{ message, .. } => format!("Parse failed: {}", message),
+            GenericParseError::Located(e, _) => e.message(),
+        }
+    }
+}
+
+// Located errors render as the file, line and 1-based column range, followed by the line itself
+// with carets under the offending text, e.g.:
+//     input/input.txt:3:1-3: Parse failed: Invalid instruction: jpm
+//       |
+//     3 | jpm +4
+//       | ^^^
 impl fmt::Display for GenericParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let err_msg = match self {
-            GenericParseError::LineError => "Could not successfully read line".to_owned(),
-            GenericParseError::ValueError(m) => format!("Parse failed: {}", m),
+        let location = match self.location() {
+            Some(location) => location,
+            None => return write!(f, "{}", self.message()),
         };
 
-        write!(f, "{}", err_msg)
+        // Work in chars rather than bytes so carets line up under non-ASCII text
+        let span = self.span().filter(|s| s.end <= location.text.len()).unwrap_or(0..location.text.len());
+        let start_col = location.text[..span.start].chars().count();
+        let width = location.text[span.clone()].chars().count().max(1);
+
+        let line_num = location.line.to_string();
+        let gutter = " ".repeat(line_num.len());
+        let columns = match width {
+            1 => (start_col + 1).to_string(),
+            _ => format!("{}-{}", start_col + 1, start_col + width),
+        };
+        writeln!(f, "{}:{}:{}: {}", location.path.display(), location.line, columns, self.message())?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_num, location.text)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(start_col), "^".repeat(width))
+    }
+}
+
+// Debug renders the same as Display, since that's what gets printed when an error is returned from main
+impl fmt::Debug for GenericParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
     }
 }
 
 impl From<std::io::Error> for GenericParseError {
-    fn from(_: std::io::Error) -> Self {
-        GenericParseError::LineError
+    fn from(error: std::io::Error) -> Self {
+        GenericParseError::LineError(error.to_string())
     }
 }
 
 impl From<std::num::ParseIntError> for GenericParseError {
     fn from(error: std::num::ParseIntError) -> Self {
-        GenericParseError::ValueError(error.to_string())
+        GenericParseError::value(error.to_string())
     }
 }
 
 impl std::error::Error for GenericParseError {}
 
+// Returns the byte range `sub` covers within `s`. `sub` must be a slice of `s`, e.g. one
+// returned by `split`, `trim` or a regex capture on it.
+pub fn subslice_span(s: &str, sub: &str) -> Range<usize> {
+    let start = sub.as_ptr() as usize - s.as_ptr() as usize;
+    assert!(start + sub.len() <= s.len(), "subslice_span: `sub` is not a slice of `s`");
+    start..(start + sub.len())
+}
+
+// Parses `sub` (a slice of the line `s`), pointing any error at where `sub` sits in the line
+pub fn parse_subslice<T>(s: &str, sub: &str) -> std::result::Result<T, GenericParseError>
+    where T: FromStr, T::Err: fmt::Display {
+    sub.parse::<T>().map_err(|e| GenericParseError::value_in(e.to_string(), s, sub))
+}
+
+// Record is a group of consecutive non-blank lines, as found in inputs that separate their
+// entries (passports, tiles, decks, ...) with blank lines
+#[derive(Debug, Clone)]
+pub struct Record<'a> {
+    pub path: &'a Path,
+    pub first_line: usize, // 1-based line number of `lines[0]` within the file
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Record<'a> {
+    // Attaches the location of the record's `idx`th line to `e`
+    pub fn locate(&self, idx: usize, e: GenericParseError) -> GenericParseError {
+        e.locate(self.path, self.first_line + idx, self.lines.get(idx).copied().unwrap_or(""))
+    }
+
+    // Parses the record's `idx`th line, pointing any error at that line
+    pub fn parse_line<T: FromStr<Err = GenericParseError>>(&self, idx: usize) -> std::result::Result<T, GenericParseError> {
+        match self.lines.get(idx) {
+            Some(line) => line.parse::<T>().map_err(|e| self.locate(idx, e)),
+            None => Err(self.locate(self.lines.len() - 1, GenericParseError::value(
+                format!("Record ends before line {}", idx + 1),
+            ))),
+        }
+    }
+}
+
+// To use read_records_to_type, make sure your struct implements FromRecord. This is the
+// record equivalent of implementing FromStr for read_lines_to_type.
+pub trait FromRecord: Sized {
+    fn from_record(record: &Record) -> std::result::Result<Self, GenericParseError>;
+}
+
+// Splits `contents` (read from `path`) into records. Lines containing only whitespace count as
+// blank, so CRLF line endings, runs of blank lines and a missing trailing blank line are all fine.
+pub fn split_records<'a>(path: &'a Path, contents: &'a str) -> Vec<Record<'a>> {
+    let mut out: Vec<Record> = vec![];
+    let mut curr: Option<Record> = None;
+    for (idx, line) in contents.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        match (line.trim().is_empty(), curr.as_mut()) {
+            (true, _) => out.extend(curr.take()),
+            (false, Some(record)) => record.lines.push(line),
+            (false, None) => curr = Some(Record{ path, first_line: idx + 1, lines: vec![line] }),
+        }
+    }
+
+    out.extend(curr);
+    out
+}
+
+// Reads the file at `path` as groups of lines, one per record
+pub fn read_records(path: PathBuf) -> Result<Vec<Vec<String>>> {
+    let contents = read_to_string(path.clone())?;
+    Ok(split_records(&path, &contents).into_iter().map(|record| {
+        record.lines.into_iter().map(|l| l.to_owned()).collect()
+    }).collect())
+}
+
+// Errors that weren't already located by `from_record` are pointed at the record's first line
+pub fn read_records_to_type<T: FromRecord>(path: PathBuf) -> Result<Vec<T>> {
+    let contents = read_to_string(path.clone())?;
+    split_records(&path, &contents).iter().map(|record| {
+        T::from_record(record).map_err(|e| record.locate(0, e).into())
+    }).collect()
+}
+
+// LineIter lazily reads and parses a file one line at a time, yielding a Result per line
+// with the file and line attached to any error. Build one with iter_lines_to_type.
+pub struct LineIter<T> {
+    path: PathBuf,
+    lines: std::iter::Enumerate<std::io::Lines<Box<dyn BufRead>>>,
+    skip_comments_and_blanks: bool,
+    _parsed: PhantomData<T>,
+}
+
+impl<T> LineIter<T> {
+    // Skips blank lines and lines starting with `#` (after any leading whitespace) instead of
+    // parsing them. Not for inputs where `#` is part of the puzzle, like seat or tile maps.
+    pub fn skip_comments_and_blanks(mut self) -> Self {
+        self.skip_comments_and_blanks = true;
+        self
+    }
+}
+
+impl<T: FromStr> Iterator for LineIter<T> where GenericParseError: From<T::Err> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        for (idx, line) in &mut self.lines {
+            let l = match line {
+                Ok(l) => l,
+                Err(e) => return Some(Err(GenericParseError::LineError(e.to_string()).locate(&self.path, idx + 1, "").into())),
+            };
+
+            let trimmed = l.trim_start();
+            if self.skip_comments_and_blanks && (trimmed.is_empty() || trimmed.starts_with('#')) { continue }
+            return Some(l.parse::<T>().map_err(|e| GenericParseError::from(e).locate(&self.path, idx + 1, &l).into()))
+        }
+
+        None
+    }
+}
+
+// Works for any T whose FromStr error converts to GenericParseError, which includes the
+// integer types as well as structs implementing FromStr with `type Err = GenericParseError;`
+pub fn iter_lines_to_type<T: FromStr>(path: PathBuf) -> Result<LineIter<T>> where GenericParseError: From<T::Err> {
+    Ok(LineIter{
+        lines: open(&path)?.lines().enumerate(),
+        path,
+        skip_comments_and_blanks: false,
+        _parsed: PhantomData,
+    })
+}
+
 // To use read_lines_to_type, make sure your struct implements FromStr with:
 // type Err = GenericParseError;
+// Errors are returned with the file and line they happened on attached.
 pub fn read_lines_to_type<T: FromStr<Err = GenericParseError>>(path: PathBuf) -> Result<Vec<T>> {
-    let f = File::open(path)?;
-    let reader = BufReader::new(f);
-
-    reader.lines().map(|line| {
-        line.map_or(Err(GenericParseError::LineError), |l| l.parse::<T>()).map_err(|e| e.into())
-    }).collect()
+    iter_lines_to_type(path)?.collect()
 }
 
 pub fn read_lines_to_integers<T: FromStr<Err = std::num::ParseIntError>>(path: PathBuf) -> Result<Vec<T>> {
-    let f = File::open(path)?;
-    let reader = BufReader::new(f);
+    iter_lines_to_type(path)?.collect()
+}
 
-    reader.lines().map(|line| {
-        line.map_or(Err(From::from("Bad line")), |l| l.parse::<T>().map_err(|e| e.into()))
-    }).collect()
-}
\ No newline at end of file
+// Like read_lines_to_type, for files that can have `#` comments and blank lines between the
+// lines that matter. Use iter_lines_to_type directly to look at lines as they're read instead.
+pub fn read_data_lines<T: FromStr>(path: PathBuf) -> Result<Vec<T>> where GenericParseError: From<T::Err> {
+    iter_lines_to_type(path)?.skip_comments_and_blanks().collect()
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::Path;
+use super::answers::{read_expected_answers, ExpectedAnswer};
+use super::res::{Context, Result};
+use super::solution::Answer;
+
+// Generators make random puzzle inputs for a day, along with the answers they're known to
+// have (under the day's default params). Where it can be done, the answer is planted while
+// building the input (e.g. a timestamp that a bus schedule is built around), so it doesn't
+// come from running a solution. Answers that could only be found by simulating the puzzle
+// are left unknown.
+
+// Rng is a small seeded random number generator (SplitMix64). It's kept here rather than
+// pulled from a crate so that a seed makes the same input for as long as this code doesn't change.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng{ state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A number in [0, n), without the bias of taking a plain remainder
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below needs a non-empty range");
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone { return x % n }
+        }
+    }
+
+    // A number in [lo, hi]
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "Rng::range needs lo <= hi, got {}..={}", lo, hi);
+        match (hi - lo) as u64 {
+            u64::MAX => self.next_u64() as i64,
+            span => lo + self.below(span + 1) as i64,
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    // True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    // `count` different items out of `items`, in random order
+    pub fn sample<T: Clone>(&mut self, items: &[T], count: usize) -> Vec<T> {
+        let mut items = items.to_vec();
+        self.shuffle(&mut items);
+        items.truncate(count);
+        items
+    }
+}
+
+// Generated is a puzzle input, with the answers to each part that are known for it
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<Answer>; 2],
+}
+
+impl Generated {
+    pub fn new(input: String, part1: Option<Answer>, part2: Option<Answer>) -> Self {
+        Generated{ input, answers: [part1, part2] }
+    }
+
+    // The known answers as `answers.txt` lines for an input file named `name`
+    pub fn answer_lines(&self, name: &str) -> Vec<String> {
+        self.answers.iter().zip(1..).filter_map(|(answer, part)| {
+            answer.as_ref().map(|a| format!("{} {} {}", name, part, a))
+        }).collect()
+    }
+
+    // Writes the input to `dir/name`, and records its known answers in `dir/answers.txt` (in
+    // place of any answers already there for `name`), so that it can be checked like any other input
+    pub fn write_to(&self, dir: &Path, name: &str) -> Result<()> {
+        fs::create_dir_all(dir).with_context(|| format!("Creating {}", dir.display()))?;
+        let input_path = dir.join(name);
+        fs::write(&input_path, &self.input).with_context(|| format!("Writing {}", input_path.display()))?;
+
+        let answers_path = dir.join("answers.txt");
+        let mut lines = match answers_path.exists() {
+            true => {
+                // Make sure the existing file is valid before rewriting it
+                read_expected_answers(answers_path.clone())?;
+                let contents = fs::read_to_string(&answers_path).with_context(|| format!("Reading {}", answers_path.display()))?;
+                contents.lines().filter(|l| {
+                    // Answers for `name` run with other params go too, since they're for the old input
+                    l.parse::<ExpectedAnswer>().map_or(true, |expected| expected.input != name)
+                }).map(|l| l.to_owned()).collect()
+            },
+            false => vec![],
+        };
+
+        lines.extend(self.answer_lines(name));
+        let contents = lines.iter().map(|l| format!("{}\n", l)).collect::<String>();
+        fs::write(&answers_path, contents).with_context(|| format!("Writing {}", answers_path.display()))
+    }
+}
@@ -0,0 +1,266 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use super::res::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError<N> {
+    // The nodes of a cycle, in order, starting and ending at the same node
+    Cycle(Vec<N>),
+}
+
+impl<N: fmt::Display> fmt::Display for GraphError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::Cycle(nodes) => {
+                let nodes = nodes.iter().map(|n| n.to_string()).collect::<Vec<String>>();
+                write!(f, "Cycle: {}", nodes.join(" -> "))
+            },
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for GraphError<N> {}
+
+// A cycle means the input doesn't describe the acyclic puzzle the solution expects
+impl<N: fmt::Display> From<GraphError<N>> for Error {
+    fn from(error: GraphError<N>) -> Self {
+        Error::InvalidPuzzle(error.to_string())
+    }
+}
+
+pub type GraphResult<T, N> = std::result::Result<T, GraphError<N>>;
+
+// Graph is a directed graph stored as adjacency lists, with a weight on every edge. Nodes can
+// be anything hashable, and a node exists once it's been added or is at either end of an edge.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    edges: HashMap<N, Vec<(N, u64)>>,
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Graph{ edges: HashMap::new() }
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, weight: u64) {
+        self.add_node(to.clone());
+        self.edges.entry(from).or_default().push((to, weight));
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.edges.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+
+    // The nodes `node` has edges to, with their weights
+    pub fn neighbours<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, u64)> {
+        self.edges.get(node).into_iter().flatten().map(|(n, w)| (n, *w))
+    }
+
+    // The same graph with every edge pointing the other way
+    pub fn reversed(&self) -> Graph<N> {
+        let mut out = Graph::new();
+        for (from, edges) in &self.edges {
+            out.add_node(from.clone());
+            edges.iter().for_each(|(to, weight)| out.add_edge(to.clone(), from.clone(), *weight));
+        }
+
+        out
+    }
+
+    // Every node reachable from `start` (including itself), with the fewest edges needed to reach it
+    pub fn bfs(&self, start: &N) -> HashMap<N, usize> {
+        let mut hops: HashMap<N, usize> = vec![(start.clone(), 0)].into_iter().collect();
+        let mut to_visit: VecDeque<N> = vec![start.clone()].into_iter().collect();
+        while let Some(visited) = to_visit.pop_front() {
+            let next_hops = hops[&visited] + 1;
+            for (neighbour, _) in self.neighbours(&visited) {
+                if !hops.contains_key(neighbour) {
+                    hops.insert(neighbour.clone(), next_hops);
+                    to_visit.push_back(neighbour.clone());
+                }
+            }
+        }
+
+        hops
+    }
+
+    pub fn reachable(&self, start: &N) -> HashSet<N> {
+        self.bfs(start).into_keys().collect()
+    }
+
+    // Every node reachable from `start`, in the order a depth-first search first visits them
+    pub fn dfs(&self, start: &N) -> Vec<N> {
+        let mut seen: HashSet<N> = HashSet::new();
+        let mut order = vec![];
+        let mut to_visit = vec![start.clone()];
+        while let Some(visited) = to_visit.pop() {
+            if !seen.insert(visited.clone()) { continue }
+            // Pushed in reverse so that neighbours are visited in the order their edges were added
+            let neighbours = self.neighbours(&visited).map(|(n, _)| n.clone()).collect::<Vec<N>>();
+            to_visit.extend(neighbours.into_iter().rev().filter(|n| !seen.contains(n)));
+            order.push(visited);
+        }
+
+        order
+    }
+
+    // Dijkstra's algorithm: the total weight of the lightest path from `start` to every node it
+    // can reach, along with the node before each one on that path
+    pub fn shortest_paths(&self, start: &N) -> (HashMap<N, u64>, HashMap<N, N>) where N: Ord {
+        let mut distances: HashMap<N, u64> = vec![(start.clone(), 0)].into_iter().collect();
+        let mut previous: HashMap<N, N> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((0, start.clone())));
+
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if distances.get(&node).is_some_and(|&d| d < distance) { continue }
+            for (neighbour, weight) in self.neighbours(&node) {
+                let candidate = distance + weight;
+                if distances.get(neighbour).is_none_or(|&d| candidate < d) {
+                    distances.insert(neighbour.clone(), candidate);
+                    previous.insert(neighbour.clone(), node.clone());
+                    queue.push(Reverse((candidate, neighbour.clone())));
+                }
+            }
+        }
+
+        (distances, previous)
+    }
+
+    // The lightest path from `start` to `goal` and its total weight, if `goal` is reachable
+    pub fn shortest_path(&self, start: &N, goal: &N) -> Option<(u64, Vec<N>)> where N: Ord {
+        let (distances, previous) = self.shortest_paths(start);
+        let distance = *distances.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(p) = previous.get(path.last().unwrap()) {
+            path.push(p.clone());
+        }
+
+        path.reverse();
+        Some((distance, path))
+    }
+
+    // Every node, ordered so that each edge goes from an earlier node to a later one
+    pub fn topological_sort(&self) -> GraphResult<Vec<N>, N> {
+        let mut finished: HashSet<N> = HashSet::new();
+        let mut order = vec![];
+        for node in self.edges.keys() {
+            self.visit_post_order(node, &mut finished, &mut vec![], &mut |n| order.push(n.clone()))?;
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    // Computes a value for `start` from its neighbours' values, e.g. the total weight of
+    // everything under it. `f` gets each node along with its neighbours' values and edge weights.
+    // Each node's value is only computed once, however many paths lead to it.
+    pub fn aggregate<T: Clone>(&self, start: &N, f: impl Fn(&N, &[(T, u64)]) -> T) -> GraphResult<T, N> {
+        let mut values: HashMap<N, T> = HashMap::new();
+        let mut finished: HashSet<N> = HashSet::new();
+        self.visit_post_order(start, &mut finished, &mut vec![], &mut |node| {
+            let inputs = self.neighbours(node).map(|(n, w)| (values[n].clone(), w)).collect::<Vec<(T, u64)>>();
+            let value = f(node, &inputs);
+            values.insert(node.clone(), value);
+        })?;
+
+        Ok(values.remove(start).unwrap())
+    }
+
+    // Calls `f` on every node reachable from `node` that isn't finished yet, after calling it on
+    // all of that node's neighbours. `path` is the chain of nodes being visited, for finding cycles.
+    fn visit_post_order(&self, node: &N, finished: &mut HashSet<N>, path: &mut Vec<N>, f: &mut impl FnMut(&N)) -> GraphResult<(), N> {
+        if finished.contains(node) { return Ok(()) }
+        if let Some(idx) = path.iter().position(|n| n == node) {
+            let mut cycle = path[idx..].to_vec();
+            cycle.push(node.clone());
+            return Err(GraphError::Cycle(cycle))
+        }
+
+        path.push(node.clone());
+        for (neighbour, _) in self.neighbours(node) {
+            self.visit_post_order(neighbour, finished, path, f)?;
+        }
+        path.pop();
+
+        f(node);
+        finished.insert(node.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_edges(edges: &[(&'static str, &'static str, u64)]) -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        edges.iter().for_each(|&(from, to, weight)| graph.add_edge(from, to, weight));
+        graph
+    }
+
+    #[test]
+    fn shortest_paths_take_the_lightest_route() {
+        // a -> b -> c -> d is lighter than the direct a -> d or a -> c
+        let graph = from_edges(&[("a", "b", 1), ("b", "c", 2), ("a", "c", 5), ("c", "d", 1), ("a", "d", 10), ("e", "a", 1)]);
+        let (distances, previous) = graph.shortest_paths(&"a");
+        assert_eq!(distances, vec![("a", 0), ("b", 1), ("c", 3), ("d", 4)].into_iter().collect());
+        assert_eq!(previous.get("d"), Some(&"c"));
+        assert_eq!(previous.get("a"), None);
+
+        assert_eq!(graph.shortest_path(&"a", &"d"), Some((4, vec!["a", "b", "c", "d"])));
+        assert_eq!(graph.shortest_path(&"a", &"a"), Some((0, vec!["a"])));
+        assert_eq!(graph.shortest_path(&"a", &"e"), None);
+    }
+
+    #[test]
+    fn dfs_follows_edges_in_order() {
+        let graph = from_edges(&[("a", "b", 1), ("a", "c", 1), ("b", "d", 1), ("c", "d", 1), ("d", "a", 1)]);
+        assert_eq!(graph.dfs(&"a"), vec!["a", "b", "d", "c"]);
+        assert_eq!(graph.dfs(&"c"), vec!["c", "d", "a", "b"]);
+    }
+
+    #[test]
+    fn topological_sort_puts_every_edge_forwards() {
+        let graph = from_edges(&[("shirt", "tie", 1), ("tie", "jacket", 1), ("trousers", "shoes", 1), ("trousers", "belt", 1), ("belt", "jacket", 1), ("socks", "shoes", 1)]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), 7);
+        let position = |node: &str| order.iter().position(|n| *n == node).unwrap();
+        for node in graph.nodes() {
+            for (neighbour, _) in graph.neighbours(node) {
+                assert!(position(node) < position(neighbour), "{} should come before {} in {:?}", node, neighbour, order);
+            }
+        }
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let graph = from_edges(&[("a", "b", 1), ("b", "c", 1), ("c", "a", 1)]);
+        match graph.topological_sort() {
+            Err(GraphError::Cycle(cycle)) => {
+                assert_eq!(cycle.len(), 4);
+                assert_eq!(cycle.first(), cycle.last());
+                assert!(cycle.windows(2).all(|pair| graph.neighbours(&pair[0]).any(|(n, _)| *n == pair[1])), "{:?}", cycle);
+            },
+            other => panic!("Expected a cycle, got {:?}", other),
+        }
+
+        let looped = from_edges(&[("a", "a", 1)]);
+        assert_eq!(looped.aggregate(&"a", |_, inputs: &[(u64, u64)]| inputs.len() as u64), Err(GraphError::Cycle(vec!["a", "a"])));
+        assert_eq!(GraphError::Cycle(vec!["a", "b", "a"]).to_string(), "Cycle: a -> b -> a");
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::path::PathBuf;
+use super::file::{GenericParseError, Record};
+use super::res::Result;
+
+// (row, col) offsets to the neighbours of a cell, in clockwise order starting from above it
+const OFFSETS_4: [(i64, i64); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const OFFSETS_8: [(i64, i64); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+// Grid is a fixed-size 2D map of cells, stored row by row and indexed by (row, col) with
+// (0, 0) at the top left, the way the puzzle inputs are laid out
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self where T: Clone {
+        Grid{ width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|r| (0..width).map(move |c| (r, c))).map(|(r, c)| f(r, c)).collect();
+        Grid{ width, height, cells }
+    }
+
+    // Parses a record's lines as a grid, with `f` mapping each char to a cell, or to None if
+    // it isn't valid. Every line must be the same width.
+    pub fn from_record(record: &Record, f: impl Fn(char) -> Option<T>) -> std::result::Result<Self, GenericParseError> {
+        let width = record.lines.first().map_or(0, |l| l.chars().count());
+        let mut cells = Vec::with_capacity(width * record.lines.len());
+        for (idx, line) in record.lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(record.locate(idx, GenericParseError::value(
+                    format!("Expected a row {} wide, found {}", width, line.chars().count()),
+                )));
+            }
+
+            for (byte_idx, c) in line.char_indices() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(record.locate(idx, GenericParseError::value_at(
+                        format!("Invalid character: {}", c), byte_idx..(byte_idx + c.len_utf8()),
+                    ))),
+                }
+            }
+        }
+
+        Ok(Grid{ width, height: record.lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize { self.width }
+    pub fn height(&self) -> usize { self.height }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width { self.cells.get(row * self.width + col) } else { None }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.height && col < self.width { self.cells.get_mut(row * self.width + col) } else { None }
+    }
+
+    // Gets a cell as if the grid repeated forever in every direction. Only None for an empty grid,
+    // which has nothing to repeat.
+    pub fn get_wrapping(&self, row: i64, col: i64) -> Option<&T> {
+        self.wrap(row, col).map(|pos| &self[pos])
+    }
+
+    fn wrap(&self, row: i64, col: i64) -> Option<(usize, usize)> {
+        if self.cells.is_empty() { return None }
+        Some((row.rem_euclid(self.height as i64) as usize, col.rem_euclid(self.width as i64) as usize))
+    }
+
+    fn offset(&self, (row, col): (usize, usize), (row_off, col_off): (i64, i64)) -> Option<(usize, usize)> {
+        let (r, c) = (row as i64 + row_off, col as i64 + col_off);
+        if r < 0 || c < 0 || r >= self.height as i64 || c >= self.width as i64 { None } else { Some((r as usize, c as usize)) }
+    }
+
+    // All (row, col) positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The positions above, right of, below and left of a cell that are inside the grid
+    pub fn neighbours_4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4.iter().filter_map(move |&o| self.offset(pos, o))
+    }
+
+    // The positions of the (up to) 8 cells surrounding a cell, including diagonals
+    pub fn neighbours_8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.iter().filter_map(move |&o| self.offset(pos, o))
+    }
+
+    // Like neighbours_4, but wrapping around the edges of the grid so every cell has 4 neighbours
+    pub fn neighbours_4_wrapping(&self, (row, col): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4.iter().filter_map(move |&(r, c)| self.wrap(row as i64 + r, col as i64 + c))
+    }
+
+    // Like neighbours_8, but wrapping around the edges of the grid so every cell has 8 neighbours
+    pub fn neighbours_8_wrapping(&self, (row, col): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.iter().filter_map(move |&(r, c)| self.wrap(row as i64 + r, col as i64 + c))
+    }
+
+    // The positions met walking from `pos` (exclusive) one `step` at a time until leaving the grid
+    pub fn ray(&self, pos: (usize, usize), step: (i64, i64)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.offset(pos, step), move |&p| self.offset(p, step))
+    }
+
+    // The offsets used by neighbours_8, for walking rays in every direction
+    pub fn directions_8() -> &'static [(i64, i64)] {
+        &OFFSETS_8
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[(row * self.width)..((row + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |r| self.row(r))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "Column {} is outside a grid {} wide", col, self.width);
+        (0..self.height).map(move |r| &self.cells[r * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(move |c| self.column(c))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid{ width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn count(&self, f: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|c| f(c)).count()
+    }
+
+    // The `height` x `width` grid whose top left cell is at `top_left`
+    pub fn sub_grid(&self, top_left: (usize, usize), height: usize, width: usize) -> Grid<T> where T: Clone {
+        Grid::from_fn(height, width, |r, c| self[(top_left.0 + r, top_left.1 + c)].clone())
+    }
+
+    // Rotates a quarter turn counterclockwise, so the right column becomes the top row
+    pub fn rotate_left(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.width, self.height, |r, c| self[(c, self.width - 1 - r)].clone())
+    }
+
+    // Rotates a quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_right(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.width, self.height, |r, c| self[(self.height - 1 - c, r)].clone())
+    }
+
+    // Mirrors left to right
+    pub fn flip_horizontal(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.height, self.width, |r, c| self[(r, self.width - 1 - c)].clone())
+    }
+
+    // Mirrors top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> where T: Clone {
+        Grid::from_fn(self.height, self.width, |r, c| self[(self.height - 1 - r, c)].clone())
+    }
+
+    // All 8 ways of rotating and flipping the grid: its 4 rotations, then those of its mirror image
+    pub fn orientations(&self) -> Vec<Grid<T>> where T: Clone {
+        let flipped = self.flip_horizontal();
+        [self.clone(), flipped].iter().flat_map(|start| {
+            std::iter::successors(Some(start.clone()), |g| Some(g.rotate_left())).take(4)
+        }).collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", row, col, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(row, col).unwrap_or_else(|| panic!("({}, {}) is outside a {}x{} grid", row, col, height, width))
+    }
+}
+
+// Grids display one line per row, with each cell's Display output one after another
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Reads a whole file as a single grid. Trailing blank lines are ignored.
+pub fn read_grid<T>(path: PathBuf, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+    let contents = super::file::read_to_string(path.clone())?;
+    let mut lines = contents.lines().collect::<Vec<&str>>();
+    while lines.last().is_some_and(|l| l.trim().is_empty()) { lines.pop(); }
+
+    Ok(Grid::from_record(&Record{ path: &path, first_line: 1, lines }, f)?)
+}
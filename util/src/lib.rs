@@ -1,2 +1,21 @@
+pub mod answers;
+pub mod assignment;
+pub mod automaton;
+pub mod bench;
+pub mod differential;
 pub mod file;
+pub mod gen;
+pub mod graph;
+pub mod grid;
+pub mod math;
+pub mod params;
+pub mod render;
+pub mod report;
 pub mod res;
+pub mod solution;
+pub mod trace;
+
+pub use params::{ParamSpec, Params};
+pub use render::Renderer;
+pub use res::{Context, Error};
+pub use solution::{Alternative, Answer, Solution};
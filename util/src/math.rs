@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fmt;
+use super::res::Error;
+
+// Number theory helpers. Everything works on i128 so that puzzle values (which fit in an i64)
+// can be multiplied without overflowing, and results are always reduced into [0, modulus).
+// Moduli must be positive; passing anything else is a bug in the caller and panics.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MathError {
+    // The equation(s) have no solution, e.g. a CRT system whose congruences contradict each other
+    NoSolution(String),
+    // The answer doesn't fit in an i128
+    Overflow(String),
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::NoSolution(m) => write!(f, "{}", m),
+            MathError::Overflow(m) => write!(f, "Overflow: {}", m),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::NoSolution(m) => Error::NoSolution(m),
+            e => Error::InvalidPuzzle(e.to_string()),
+        }
+    }
+}
+
+pub type MathResult<T> = std::result::Result<T, MathError>;
+
+fn check_modulus(modulus: i128) {
+    assert!(modulus > 0, "Modulus must be positive, got {}", modulus);
+}
+
+pub fn gcd(a: i128, b: i128) -> i128 {
+    extended_gcd(a, b).0
+}
+
+// Returns (gcd, x, y) such that ax + by = gcd, with gcd >= 0
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+pub fn lcm(a: i128, b: i128) -> MathResult<i128> {
+    if a == 0 || b == 0 { return Ok(0) }
+    (a / gcd(a, b)).checked_mul(b).map(i128::abs)
+        .ok_or_else(|| MathError::Overflow(format!("lcm({}, {})", a, b)))
+}
+
+pub fn mod_add(a: i128, b: i128, modulus: i128) -> i128 {
+    check_modulus(modulus);
+    let (a, b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    // a + b can only overflow if the modulus is within a factor of 2 of i128::MAX
+    match a.checked_add(b) {
+        Some(sum) => sum % modulus,
+        None => a - (modulus - b),
+    }
+}
+
+pub fn mod_sub(a: i128, b: i128, modulus: i128) -> i128 {
+    mod_add(a, modulus - b.rem_euclid(modulus), modulus)
+}
+
+// Falls back to double-and-add when the plain product would overflow, so it's correct for any modulus
+pub fn mod_mul(a: i128, b: i128, modulus: i128) -> i128 {
+    check_modulus(modulus);
+    let (mut a, mut b) = (a.rem_euclid(modulus), b.rem_euclid(modulus));
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus
+    }
+
+    let mut out = 0;
+    while b > 0 {
+        if b & 1 == 1 { out = mod_add(out, a, modulus); }
+        a = mod_add(a, a, modulus);
+        b >>= 1;
+    }
+    out
+}
+
+// base ^ exponent (mod modulus), by repeated squaring
+pub fn mod_pow(base: i128, exponent: u128, modulus: i128) -> i128 {
+    check_modulus(modulus);
+    let (mut out, mut base, mut exponent) = (1 % modulus, base.rem_euclid(modulus), exponent);
+    while exponent > 0 {
+        if exponent & 1 == 1 { out = mod_mul(out, base, modulus); }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    out
+}
+
+// The x such that ax ≡ 1 (mod modulus), which only exists if a and the modulus are coprime
+pub fn mod_inverse(a: i128, modulus: i128) -> MathResult<i128> {
+    check_modulus(modulus);
+    match extended_gcd(a.rem_euclid(modulus), modulus) {
+        (1, x, _) => Ok(x.rem_euclid(modulus)),
+        (g, _, _) => Err(MathError::NoSolution(format!("{} has no inverse mod {} (gcd is {})", a, modulus, g))),
+    }
+}
+
+// Solves ax ≡ b (mod modulus) for x. Since a and the modulus needn't be coprime, the
+// solutions are every x ≡ residue (mod reduced_modulus), returned as (residue, reduced_modulus).
+pub fn solve_linear_congruence(a: i128, b: i128, modulus: i128) -> MathResult<(i128, i128)> {
+    check_modulus(modulus);
+    let g = gcd(a.rem_euclid(modulus), modulus);
+    if b.rem_euclid(g) != 0 {
+        return Err(MathError::NoSolution(format!("{}x ≡ {} (mod {}) has no solution", a, b, modulus)))
+    }
+
+    let reduced = modulus / g;
+    let inverse = mod_inverse(a / g, reduced)?;
+    Ok((mod_mul(b / g, inverse, reduced), reduced))
+}
+
+// Chinese Remainder Theorem: finds the x satisfying x ≡ residue (mod modulus) for every
+// (residue, modulus) pair, returned as (x, lcm of the moduli) with x the smallest non-negative
+// solution. The moduli don't need to be coprime, but then the congruences might contradict
+// each other, which is reported as NoSolution.
+pub fn crt(congruences: &[(i128, i128)]) -> MathResult<(i128, i128)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        check_modulus(m2);
+        // x = r1 + m1 * k, so we need m1 * k ≡ r2 - r1 (mod m2)
+        let (k, _) = solve_linear_congruence(m1, mod_sub(r2, r1, m2), m2).map_err(|_| {
+            MathError::NoSolution(format!("x ≡ {} (mod {}) contradicts x ≡ {} (mod {})", r2, m2, r1, m1))
+        })?;
+
+        let combined = lcm(m1, m2)?;
+        let folded = mod_add(r1, mod_mul(m1, k, combined), combined);
+        crate::trace!(Debug, "crt.fold", x = (r1, m1), with = (r2, m2), gives = (folded, combined));
+        Ok((folded, combined))
+    })
+}
+
+// Baby-step giant-step discrete log: the smallest x >= 0 with base^x ≡ target (mod modulus), in
+// O(sqrt(modulus)) time and space. Always correct when base and modulus are coprime; otherwise
+// a solution can occasionally be missed.
+pub fn discrete_log(base: i128, target: i128, modulus: i128) -> MathResult<u128> {
+    check_modulus(modulus);
+    let target = target.rem_euclid(modulus);
+    let n = (modulus as f64).sqrt().ceil() as u128 + 1;
+
+    // Baby steps: check small exponents directly, and remember target * base^j for each j
+    let mut baby_steps: HashMap<i128, u128> = HashMap::new();
+    let (mut power, mut shifted_target) = (1 % modulus, target);
+    for j in 0..n {
+        if power == target { return Ok(j) }
+        baby_steps.insert(shifted_target, j);
+        power = mod_mul(power, base, modulus);
+        shifted_target = mod_mul(shifted_target, base, modulus);
+    }
+
+    // Giant steps: base^(i * n) ≡ target * base^j means x = i * n - j is a candidate
+    let giant_step = mod_pow(base, n, modulus);
+    let mut giant = giant_step;
+    for i in 1..=n {
+        if let Some(&j) = baby_steps.get(&giant) {
+            let x = i * n - j;
+            if mod_pow(base, x, modulus) == target { return Ok(x) }
+        }
+        giant = mod_mul(giant, giant_step, modulus);
+    }
+
+    Err(MathError::NoSolution(format!("No x with {}^x ≡ {} (mod {})", base, target, modulus)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crt_with_coprime_moduli() {
+        // The bus schedule `17,x,13,19` from day 13's example
+        assert_eq!(crt(&[(0, 17), (-2, 13), (-3, 19)]), Ok((3417, 4199)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+    }
+
+    #[test]
+    fn crt_with_non_coprime_moduli() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Ok((10, 12)));
+        assert_eq!(crt(&[(3, 6), (3, 6), (1, 2)]), Ok((3, 6)));
+    }
+
+    #[test]
+    fn crt_with_contradictory_congruences() {
+        assert!(matches!(crt(&[(1, 4), (2, 6)]), Err(MathError::NoSolution(_))));
+        assert!(matches!(crt(&[(0, 3), (1, 3)]), Err(MathError::NoSolution(_))));
+    }
+
+    #[test]
+    fn mod_inverse_exists_only_for_coprime_values() {
+        assert_eq!(mod_inverse(3, 11), Ok(4));
+        assert_eq!(mod_inverse(-3, 11), Ok(7));
+        assert!(matches!(mod_inverse(6, 9), Err(MathError::NoSolution(_))));
+        assert!(matches!(mod_inverse(0, 5), Err(MathError::NoSolution(_))));
+    }
+
+    #[test]
+    fn mod_mul_near_the_limits() {
+        let m = i128::MAX;
+        assert_eq!(mod_mul(m - 1, m - 1, m), 1);
+        assert_eq!(mod_mul(m - 1, m - 2, m), 2);
+        assert_eq!(mod_mul(m - 1, 2, m), m - 2);
+        assert_eq!(mod_mul(i128::MIN, 1, m), m - 1);
+        assert_eq!(mod_mul(-1, -1, 7), 1);
+        assert_eq!(mod_pow(2, 127, m), 1);
+    }
+
+    #[test]
+    fn discrete_log_finds_the_smallest_exponent() {
+        // The card and door public keys from day 25's example
+        assert_eq!(discrete_log(7, 5764801, 20201227), Ok(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Ok(11));
+        assert_eq!(discrete_log(2, 4, 7), Ok(2));
+        assert_eq!(discrete_log(2, 1, 7), Ok(0));
+        assert_eq!(discrete_log(3, 3 + 7, 7), Ok(1));
+    }
+
+    #[test]
+    fn discrete_log_without_a_solution() {
+        // Powers of 2 mod 7 are only ever 1, 2 and 4
+        assert!(matches!(discrete_log(2, 3, 7), Err(MathError::NoSolution(_))));
+    }
+}
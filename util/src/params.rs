@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use super::res::{Error, Result};
+
+// ParamSpec documents one of a day's puzzle constants that can be overridden with `--param`.
+// `default` is written the same way as a value given on the command line.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamSpec {
+    pub key: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+// Param is a single `key=value` override given on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for Param {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok(Param{ key: key.to_owned(), value: value.to_owned() }),
+            _ => Err(format!("Expected key=value, got: {}", s)),
+        }
+    }
+}
+
+// Params holds the value of each of a day's ParamSpecs: the override given for it, or its
+// default
+#[derive(Debug, Clone)]
+pub struct Params {
+    specs: &'static [ParamSpec],
+    overrides: HashMap<String, String>,
+}
+
+impl Params {
+    // Fails if any override isn't for one of `specs`, so that typos don't silently do nothing
+    pub fn new(specs: &'static [ParamSpec], overrides: &[Param]) -> Result<Params> {
+        if let Some(unknown) = overrides.iter().find(|p| !specs.iter().any(|s| s.key == p.key)) {
+            let known = specs.iter().map(|s| s.key).collect::<Vec<&str>>();
+            return Err(Error::usage(format!(
+                "Unknown param: {} (expected one of: {})",
+                unknown.key,
+                if known.is_empty() { "none".to_owned() } else { known.join(", ") },
+            )));
+        }
+
+        Ok(Params{
+            specs,
+            overrides: overrides.iter().map(|p| (p.key.clone(), p.value.clone())).collect(),
+        })
+    }
+
+    pub fn defaults(specs: &'static [ParamSpec]) -> Params {
+        Params{ specs, overrides: HashMap::new() }
+    }
+
+    fn raw(&self, key: &str) -> Result<&str> {
+        match self.overrides.get(key) {
+            Some(value) => Ok(value),
+            None => self.specs.iter().find(|s| s.key == key).map(|s| s.default)
+                .ok_or_else(|| Error::usage(format!("Param {} has no ParamSpec", key))),
+        }
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T> where T::Err: fmt::Display {
+        let raw = self.raw(key)?;
+        raw.parse::<T>().map_err(|e| Error::usage(format!("Invalid value for param {}: {} ({})", key, raw, e)))
+    }
+
+    // Reads a comma-separated list of values
+    pub fn get_list<T: FromStr>(&self, key: &str) -> Result<Vec<T>> where T::Err: fmt::Display {
+        let raw = self.raw(key)?;
+        raw.split(',').map(|item| {
+            item.trim().parse::<T>().map_err(|e| Error::usage(format!("Invalid value for param {}: {} ({})", key, item, e)))
+        }).collect()
+    }
+}
+
+impl fmt::Display for ParamSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (default {}): {}", self.key, self.default, self.description)
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::grid::Grid;
+use super::res::{Context, Result};
+
+pub type Rgb = (u8, u8, u8);
+
+const BLACK: Rgb = (0, 0, 0);
+const WHITE: Rgb = (255, 255, 255);
+const GREY: Rgb = (128, 128, 128);
+
+// Render is implemented by anything a grid cell can be drawn as: a character for ASCII
+// output, and a colour for images
+pub trait Render {
+    fn glyph(&self) -> char;
+    fn color(&self) -> Rgb;
+}
+
+// The way puzzle inputs draw on/off pixels
+impl Render for bool {
+    fn glyph(&self) -> char { if *self { '#' } else { '.' } }
+    fn color(&self) -> Rgb { if *self { BLACK } else { WHITE } }
+}
+
+// None is a gap, e.g. a spot on a sparse lattice with no cell, or a tile that isn't placed yet
+impl<T: Render> Render for Option<T> {
+    fn glyph(&self) -> char { self.as_ref().map_or(' ', Render::glyph) }
+    fn color(&self) -> Rgb { self.as_ref().map_or(GREY, Render::color) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ascii,
+    // Plain (P1) bitmaps, where each cell is black or white depending on how dark its colour is
+    Pbm,
+    // Plain (P3) colour images
+    Ppm,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Ascii => "txt",
+            Format::Pbm => "pbm",
+            Format::Ppm => "ppm",
+        }
+    }
+
+    fn encode<T: Render>(&self, grid: &Grid<T>) -> String {
+        match self {
+            Format::Ascii => ascii(grid),
+            Format::Pbm => pbm(grid),
+            Format::Ppm => ppm(grid),
+        }
+    }
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(Format::Ascii),
+            "pbm" => Ok(Format::Pbm),
+            "ppm" => Ok(Format::Ppm),
+            _ => Err(format!("Unknown render format: {} (expected ascii, pbm or ppm)", s)),
+        }
+    }
+}
+
+pub fn ascii<T: Render>(grid: &Grid<T>) -> String {
+    grid.rows().map(|row| row.iter().map(Render::glyph).chain(std::iter::once('\n')).collect::<String>()).collect()
+}
+
+pub fn pbm<T: Render>(grid: &Grid<T>) -> String {
+    let mut out = format!("P1\n{} {}\n", grid.width(), grid.height());
+    for row in grid.rows() {
+        let bits = row.iter().map(|cell| {
+            let (r, g, b) = cell.color();
+            if (r as u32 + g as u32 + b as u32) < 3 * 128 { "1" } else { "0" }
+        }).collect::<Vec<&str>>();
+        writeln!(out, "{}", bits.join(" ")).unwrap();
+    }
+
+    out
+}
+
+pub fn ppm<T: Render>(grid: &Grid<T>) -> String {
+    let mut out = format!("P3\n{} {}\n255\n", grid.width(), grid.height());
+    for row in grid.rows() {
+        let pixels = row.iter().map(|cell| {
+            let (r, g, b) = cell.color();
+            format!("{} {} {}", r, g, b)
+        }).collect::<Vec<String>>();
+        writeln!(out, "{}", pixels.join("  ")).unwrap();
+    }
+
+    out
+}
+
+pub fn write<T: Render>(path: &Path, grid: &Grid<T>, format: Format) -> Result<()> {
+    fs::write(path, format.encode(grid)).with_context(|| format!("Writing {}", path.display()))
+}
+
+// Draws points on an unbounded plane as the smallest grid that holds all of them, with `(x, y)`
+// at column x and row y (offset so the smallest of each is 0). Spots with no point are None.
+pub fn rasterize<T: Clone>(points: impl IntoIterator<Item = ((i64, i64), T)>) -> Grid<Option<T>> {
+    let points = points.into_iter().collect::<Vec<((i64, i64), T)>>();
+    let (min_x, max_x) = (points.iter().map(|((x, _), _)| *x).min(), points.iter().map(|((x, _), _)| *x).max());
+    let (min_y, max_y) = (points.iter().map(|((_, y), _)| *y).min(), points.iter().map(|((_, y), _)| *y).max());
+    let (min_x, min_y) = (min_x.unwrap_or(0), min_y.unwrap_or(0));
+    let width = max_x.map_or(0, |max| (max - min_x + 1) as usize);
+    let height = max_y.map_or(0, |max| (max - min_y + 1) as usize);
+
+    let mut out = Grid::new(height, width, None);
+    for ((x, y), value) in points {
+        out[((y - min_y) as usize, (x - min_x) as usize)] = Some(value);
+    }
+
+    out
+}
+
+// Renderer says where and how to write frames, when rendering was asked for with `--render`
+#[derive(Debug, Clone)]
+pub struct Renderer {
+    pub dir: PathBuf,
+    pub format: Format,
+}
+
+// Frames is a numbered sequence of images of one thing changing, e.g. every generation of an
+// automaton, written as `<name>_0000.<ext>`, `<name>_0001.<ext>` and so on. When rendering is
+// off, writing does nothing, and the grid to write isn't even built.
+pub struct Frames {
+    renderer: Option<Renderer>,
+    name: String,
+    count: usize,
+}
+
+impl Frames {
+    // Frames are only written if there's a renderer, i.e. if `--render` was given
+    pub fn new(renderer: Option<&Renderer>, name: &str) -> Self {
+        Frames{ renderer: renderer.cloned(), name: name.to_owned(), count: 0 }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.renderer.is_some()
+    }
+
+    pub fn write<T: Render>(&mut self, grid: &Grid<T>) -> Result<()> {
+        self.write_with(|| grid)
+    }
+
+    // Like write, but only calls `f` for the grid if rendering is on
+    pub fn write_with<T: Render, G: std::borrow::Borrow<Grid<T>>>(&mut self, f: impl FnOnce() -> G) -> Result<()> {
+        let renderer = match &self.renderer {
+            Some(renderer) => renderer,
+            None => return Ok(()),
+        };
+
+        fs::create_dir_all(&renderer.dir).with_context(|| format!("Creating {}", renderer.dir.display()))?;
+        let path = renderer.dir.join(format!("{}_{:04}.{}", self.name, self.count, renderer.format.extension()));
+        write(&path, f().borrow(), renderer.format)?;
+        self.count += 1;
+        Ok(())
+    }
+}
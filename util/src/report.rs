@@ -0,0 +1,104 @@
+use std::fmt::Write as _;
+use serde::Serialize;
+
+// Report is a line by line account of how a solution judged its input, for days whose answer
+// counts the lines that pass some check (e.g. valid passwords): every line, with a verdict for
+// each check it went through and the reason for any failure. Printed with `--report`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    pub lines: Vec<ReportLine>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportLine {
+    pub line: usize, // 1-based
+    pub text: String,
+    pub verdicts: Vec<Verdict>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Verdict {
+    pub check: String,
+    pub passed: bool,
+    pub reason: Option<String>,
+}
+
+impl Verdict {
+    pub fn new(check: &str, result: std::result::Result<(), String>) -> Self {
+        Verdict{ check: check.to_owned(), passed: result.is_ok(), reason: result.err() }
+    }
+}
+
+// How many lines passed each check, in the order the checks first appear
+#[derive(Debug, Clone, Serialize)]
+pub struct Total {
+    pub check: String,
+    pub passed: usize,
+    pub lines: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown report format: {} (expected text or json)", s)),
+        }
+    }
+}
+
+impl Report {
+    pub fn totals(&self) -> Vec<Total> {
+        let mut totals: Vec<Total> = vec![];
+        for verdict in self.lines.iter().flat_map(|line| line.verdicts.iter()) {
+            let total = match totals.iter().position(|t| t.check == verdict.check) {
+                Some(idx) => &mut totals[idx],
+                None => {
+                    totals.push(Total{ check: verdict.check.clone(), passed: 0, lines: 0 });
+                    totals.last_mut().unwrap()
+                },
+            };
+            total.lines += 1;
+            if verdict.passed { total.passed += 1; }
+        }
+        totals
+    }
+
+    // Text puts each line's verdicts under it, and the totals at the end, e.g.:
+    //     2: 1-3 b: cdefg
+    //         range: fail (letter 'b' appears 0 times, allowed 1-3)
+    // JSON has the same lines and totals, as {"lines": [...], "totals": [...]}
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => {
+                let mut out = String::new();
+                for line in &self.lines {
+                    let _ = writeln!(out, "{}: {}", line.line, line.text);
+                    for verdict in &line.verdicts {
+                        let _ = match &verdict.reason {
+                            _ if verdict.passed => writeln!(out, "    {}: pass", verdict.check),
+                            Some(reason) => writeln!(out, "    {}: fail ({})", verdict.check, reason),
+                            None => writeln!(out, "    {}: fail", verdict.check),
+                        };
+                    }
+                }
+                for total in self.totals() {
+                    let _ = writeln!(out, "[{}] {} / {} passed", total.check, total.passed, total.lines);
+                }
+                out
+            },
+            Format::Json => {
+                let json = serde_json::json!({ "lines": &self.lines, "totals": self.totals() });
+                format!("{}\n", serde_json::to_string_pretty(&json).unwrap())
+            },
+        }
+    }
+}
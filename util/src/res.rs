@@ -1 +1,124 @@
-pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+use std::fmt;
+use super::file::GenericParseError;
+
+// Error is returned by everything in util and by every day's solution. Its variant tells apart
+// an input that couldn't be read or parsed, an input that parsed but isn't a puzzle the
+// solution can work with, and a valid puzzle the solution couldn't find an answer to.
+pub enum Error {
+    // The input couldn't be read
+    Io(std::io::Error),
+    // The input couldn't be parsed
+    Parse(GenericParseError),
+    // The input parsed, but doesn't describe a puzzle the solution can work with
+    InvalidPuzzle(String),
+    // The solution ran to completion without finding an answer
+    NoSolution(String),
+    // The command line asked for something that doesn't exist or doesn't make sense
+    Usage(String),
+    // One of the errors above, along with what was being done when it happened
+    Context(String, Box<Error>),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn invalid_puzzle<S: Into<String>>(message: S) -> Self {
+        Error::InvalidPuzzle(message.into())
+    }
+
+    pub fn no_solution<S: Into<String>>(message: S) -> Self {
+        Error::NoSolution(message.into())
+    }
+
+    pub fn usage<S: Into<String>>(message: S) -> Self {
+        Error::Usage(message.into())
+    }
+
+    // The error underneath any context that was attached to it
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context(_, e) => e.root(),
+            e => e,
+        }
+    }
+
+    // Whether the error was caused by the input rather than by the solution or the command line
+    pub fn is_input_error(&self) -> bool {
+        matches!(self.root(), Error::Io(_) | Error::Parse(_) | Error::InvalidPuzzle(_))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::InvalidPuzzle(m) => write!(f, "Invalid puzzle: {}", m),
+            Error::NoSolution(m) => write!(f, "No solution: {}", m),
+            Error::Usage(m) => write!(f, "{}", m),
+            Error::Context(context, e) => write!(f, "{}: {}", context, e),
+        }
+    }
+}
+
+// Debug renders the same as Display, since that's what gets printed when an error is returned from main
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::Context(_, e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<GenericParseError> for Error {
+    fn from(error: GenericParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(error: std::num::ParseIntError) -> Self {
+        Error::Parse(error.into())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        match error.classify() {
+            serde_json::error::Category::Io => Error::Io(error.into()),
+            _ => Error::Parse(GenericParseError::value(error.to_string())),
+        }
+    }
+}
+
+// Context attaches a description of what was being done to an error, e.g.:
+//     File::open(&path).context(format!("Opening {}", path.display()))?
+pub trait Context<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for std::result::Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|e| Error::Context(context.to_string(), Box::new(e.into())))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|e| Error::Context(f().to_string(), Box::new(e.into())))
+    }
+}
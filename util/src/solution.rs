@@ -0,0 +1,221 @@
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use super::file::Cli;
+use super::gen::{Generated, Rng};
+use super::params::{ParamSpec, Params};
+use super::render::Renderer;
+use super::report::Report;
+use super::res::{Context, Error, Result};
+
+// Answer is the typed result of solving one part of a day's puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    Empty, // For parts with no puzzle of their own (day 25 part 2)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Empty => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(val: $t) -> Self { Answer::Int(val as i128) }
+        })*
+    };
+}
+
+impl_answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self { Answer::Str(val) }
+}
+
+impl From<&str> for Answer {
+    fn from(val: &str) -> Self { Answer::Str(val.to_owned()) }
+}
+
+// Alternative is another way of computing one part's answer, e.g. a slower algorithm that's
+// simpler to trust, or one written before the part's own. It should always agree with the part,
+// which util::differential checks on generated inputs.
+pub struct Alternative<I> {
+    pub name: &'static str,
+    pub part: u8,
+    pub run: fn(&I, &Params) -> Result<Answer>,
+}
+
+// Solution is implemented by every day's puzzle. `parse` turns an input file into the
+// day's input type once, and each part computes its answer from that parsed input.
+// Puzzle constants that can be overridden with `--param` are declared in PARAMS, and each
+// part reads their values from `params`. Days that can draw their state make Frames with
+// `renderer`, which is only Some when `--render` was given.
+pub trait Solution {
+    type Input: 'static;
+    const PARAMS: &'static [ParamSpec] = &[];
+    const ALTERNATIVES: &'static [Alternative<Self::Input>] = &[];
+
+    fn parse(path: PathBuf) -> Result<Self::Input>;
+    fn part1(input: &Self::Input, params: &Params, renderer: Option<&Renderer>) -> Result<Answer>;
+    fn part2(input: &Self::Input, params: &Params, renderer: Option<&Renderer>) -> Result<Answer>;
+
+    // Makes a random input for this day, about `size` big in whatever unit suits the puzzle (or
+    // the day's own default size if None), along with the answers it's known to have
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Result<Generated> {
+        Err(Error::usage("This day has no input generator"))
+    }
+
+    // Goes through the input line by line, recording how each line fared under each check the
+    // parts make (see util::report)
+    fn report(_input: &Self::Input, _params: &Params) -> Result<Report> {
+        Err(Error::usage("This day has no report"))
+    }
+}
+
+// DynSolution is an object-safe view of a Solution, with the parsed input type erased, so
+// that solutions to different days can be stored and run side by side (e.g. by the runner)
+pub trait DynSolution {
+    fn params(&self) -> &'static [ParamSpec];
+    fn parse(&self, path: PathBuf) -> Result<Box<dyn Any>>;
+    fn part1(&self, input: &dyn Any, params: &Params, renderer: Option<&Renderer>) -> Result<Answer>;
+    fn part2(&self, input: &dyn Any, params: &Params, renderer: Option<&Renderer>) -> Result<Answer>;
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Result<Generated>;
+    fn report(&self, input: &dyn Any, params: &Params) -> Result<Report>;
+    // The part and name of each of the solution's alternatives
+    fn alternatives(&self) -> Vec<(u8, &'static str)>;
+    // Runs every alternative on the input, and describes each one that disagreed with its part
+    fn compare_alternatives(&self, input: &dyn Any, params: &Params) -> Result<Vec<String>>;
+
+    fn part(&self, input: &dyn Any, part: u8, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        match part {
+            1 => self.part1(input, params, renderer),
+            2 => self.part2(input, params, renderer),
+            _ => Err(Error::usage(format!("Invalid part: {}", part))),
+        }
+    }
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S: Solution> Erased<S> {
+    fn downcast(input: &dyn Any) -> Result<&S::Input> {
+        input.downcast_ref::<S::Input>().ok_or_else(|| Error::usage("Input was parsed by a different solution"))
+    }
+}
+
+impl<S: Solution> DynSolution for Erased<S> {
+    fn params(&self) -> &'static [ParamSpec] {
+        S::PARAMS
+    }
+
+    fn parse(&self, path: PathBuf) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(path)?))
+    }
+
+    fn part1(&self, input: &dyn Any, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        S::part1(Self::downcast(input)?, params, renderer)
+    }
+
+    fn part2(&self, input: &dyn Any, params: &Params, renderer: Option<&Renderer>) -> Result<Answer> {
+        S::part2(Self::downcast(input)?, params, renderer)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Result<Generated> {
+        S::generate(rng, size)
+    }
+
+    fn report(&self, input: &dyn Any, params: &Params) -> Result<Report> {
+        S::report(Self::downcast(input)?, params)
+    }
+
+    fn alternatives(&self) -> Vec<(u8, &'static str)> {
+        S::ALTERNATIVES.iter().map(|alternative| (alternative.part, alternative.name)).collect()
+    }
+
+    // Errors are compared by message, so a part and an alternative that both fail the same way agree
+    fn compare_alternatives(&self, input: &dyn Any, params: &Params) -> Result<Vec<String>> {
+        let describe = |result: Result<Answer>| match result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error ({})", e),
+        };
+
+        let mut disagreements = vec![];
+        for part in [1, 2].iter() {
+            let alternatives = S::ALTERNATIVES.iter().filter(|a| a.part == *part).collect::<Vec<_>>();
+            if alternatives.is_empty() { continue }
+
+            let expected = describe(self.part(input, *part, params, None));
+            for alternative in alternatives {
+                let found = describe((alternative.run)(Self::downcast(input)?, params));
+                if found != expected {
+                    disagreements.push(format!("part {} gave {}, but {} gave {}", part, expected, alternative.name, found));
+                }
+            }
+        }
+
+        Ok(disagreements)
+    }
+}
+
+pub fn erase<S: Solution + 'static>() -> Box<dyn DynSolution> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+// Parses the given input file and prints the answer to the given part, or to both parts if none is given
+pub fn print_answers(
+    solution: &dyn DynSolution, path: PathBuf, part: Option<u8>, params: &Params, renderer: Option<&Renderer>,
+) -> Result<()> {
+    let input = solution.parse(path)?;
+    for p in [1, 2].iter().filter(|&&p| part.is_none() || part == Some(p)) {
+        let answer = solution.part(input.as_ref(), *p, params, renderer).with_context(|| format!("Part {}", p))?;
+        println!("[Part {}] {}", p, answer);
+    }
+
+    Ok(())
+}
+
+// Runs a solution the way the command line options ask for: benchmarking it if `--bench`
+// was given, printing its report if `--report` was, and otherwise printing its answers.
+// `default_input` is used if no `-f` was given. With several inputs, each one's output is
+// headed by its path.
+pub fn run_with_cli(solution: &dyn DynSolution, cli: &Cli, default_input: PathBuf) -> Result<()> {
+    super::trace::set_verbosity(cli.trace);
+    let params = Params::new(solution.params(), &cli.params)?;
+    let renderer = cli.renderer();
+    let paths = cli.input_file_paths(default_input);
+    if paths.len() > 1 && (cli.bench_json.is_some() || cli.bench_baseline.is_some()) {
+        return Err(Error::usage("--bench-json and --bench-baseline only work with a single input"));
+    }
+    if paths.len() > 1 && cli.render.is_some() {
+        return Err(Error::usage("--render only works with a single input, since each would overwrite the last's frames"));
+    }
+
+    for path in &paths {
+        if paths.len() > 1 { println!("[Input] {}", path.display()); }
+        match (cli.bench, cli.report) {
+            (Some(repetitions), _) => super::bench::run_bench(solution, path.clone(), &params, repetitions, cli)?,
+            (None, Some(format)) => {
+                let input = solution.parse(path.clone())?;
+                print!("{}", solution.report(input.as_ref(), &params)?.render(format));
+            },
+            (None, None) => print_answers(solution, path.clone(), cli.part, &params, renderer.as_ref())?,
+        }
+    }
+
+    Ok(())
+}
+
+// Days are run from their own directory, so they fall back to the input file they keep there
+pub fn run<S: Solution + 'static>() -> Result<()> {
+    run_with_cli(erase::<S>().as_ref(), &Cli::from_args(), PathBuf::from("input/input.txt"))
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Tracing writes step-by-step events to stderr, for following along with a solution that's
+// giving the wrong answer. Each `-v` on the command line turns on one more level of detail.
+// Events are written with the trace! macro, e.g.:
+//     trace!(Debug, "crt.fold", residue = r, modulus = m);
+// which prints `[debug] crt.fold: residue=3 modulus=7`. The fields are only formatted if the
+// event's level is on, so tracing costs a single comparison when it's off.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    // A handful of events per part, e.g. which strategy a solver fell back to (-v)
+    Info = 1,
+    // The main steps of an algorithm, e.g. every fold of a CRT or every game of Combat (-vv)
+    Debug = 2,
+    // Everything, e.g. every instruction executed (-vvv)
+    Trace = 3,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+// Sets how many levels are on: 0 turns tracing off, and 3 or more turns on every level
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+}
+
+// Writes an event to stderr. Use trace! instead, which skips this when the level is off.
+pub fn emit(level: Level, event: &str, fields: &[(&str, &dyn fmt::Debug)]) {
+    let fields = fields.iter().map(|(key, value)| format!("{}={:?}", key, value)).collect::<Vec<String>>();
+    // Tracing is best effort, so a closed stderr isn't worth failing a solution over
+    let _ = writeln!(std::io::stderr().lock(), "[{}] {}: {}", level, event, fields.join(" "));
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                $event,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Debug)),*],
+            );
+        }
+    };
+}